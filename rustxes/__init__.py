@@ -1,3 +1,11 @@
 from .import_xes import import_xes
 from .export_xes import export_xes
-from .import_ocel import import_ocel_xml, import_ocel_xml_pm4py, import_ocel_json, import_ocel_json_pm4py, rs_ocel_to_pm4py
\ No newline at end of file
+from .xes_json import import_xes_json, export_xes_json
+from .import_csv import import_csv_log
+from .import_mxml import import_mxml
+from .pnml import import_pnml, export_pnml
+from .export_ocel import export_ocel
+from .ocel_parquet import write_ocel_parquet, read_ocel_parquet
+from .import_ocel import import_ocel_xml, import_ocel_xml_pm4py, import_ocel_json, import_ocel_json_pm4py, rs_ocel_to_pm4py
+from .ocel import object_snapshot, enrich_events_with_object_state, validate_ocel, slice_ocel, flatten_ocel, xes_to_ocel, discover_ocdfg, ocel_summary
+from .analysis import discover_dfg, variants, filter_top_variants, log_summary, discover_alpha, discover_inductive, process_tree_to_petri_net, token_replay, alignments
@@ -0,0 +1,158 @@
+import json
+from typing import Any, Optional
+from .rustxes import discover_dfg_rs, variants_rs, filter_top_variants_rs, log_summary_rs, discover_alpha_rs, discover_inductive_rs, process_tree_to_petri_net_rs, token_replay_rs, alignments_rs
+import polars
+
+
+def discover_dfg(df: polars.DataFrame, activity_col: Optional[str] = None, case_col: Optional[str] = None, timestamp_col: Optional[str] = None, performance: Optional[bool] = None) -> dict[str, polars.DataFrame]:
+    """
+     Discover the directly-follows graph of an event log
+
+     Returns a dict with the polars DataFrames 'nodes' (`activity`, `frequency`, `start_frequency`, `end_frequency`)
+     and 'edges' (`source`, `target`, `frequency` and, with `performance`, `mean_duration`, `median_duration`, `min_duration`, `max_duration` in seconds)
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+     * `timestamp_col` - Optional timestamp column used for performance annotations (default: `"time:timestamp"`)
+     * `performance` - Optional flag to add duration statistics to the edges
+
+    """
+    return discover_dfg_rs(df, activity_col, case_col, timestamp_col, performance)
+
+
+def variants(df: polars.DataFrame, activity_col: Optional[str] = None, case_col: Optional[str] = None, timestamp_col: Optional[str] = None) -> polars.DataFrame:
+    """
+     Compute the trace variants (distinct activity sequences) of an event log
+
+     Returns a polars DataFrame with the columns `variant` (list of activities), `count`, `case_ids` (list), `mean_throughput_time` (in seconds)
+     and `percentage` (of all cases), sorted by descending count
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+     * `timestamp_col` - Optional timestamp column used for throughput times (default: `"time:timestamp"`)
+
+    """
+    return variants_rs(df, activity_col, case_col, timestamp_col)
+
+
+def filter_top_variants(df: polars.DataFrame, k: Optional[int] = None, coverage: Optional[float] = None, activity_col: Optional[str] = None, case_col: Optional[str] = None) -> polars.DataFrame:
+    """
+     Filter an event log to the cases of the most frequent variants
+
+     Returns the filtered polars DataFrame. Exactly one of `k` and `coverage` has to be given.
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `k` - Optional number of most frequent variants to keep
+     * `coverage` - Optional minimal fraction of cases (between 0 and 1) covered by the kept (most frequent) variants
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+
+    """
+    return filter_top_variants_rs(df, k, coverage, activity_col, case_col)
+
+
+def log_summary(df: polars.DataFrame, activity_col: Optional[str] = None, case_col: Optional[str] = None, timestamp_col: Optional[str] = None, resource_col: Optional[str] = None, time_bucket: Optional[str] = None) -> dict[str, polars.DataFrame]:
+    """
+     Summarize an event log
+
+     Returns a dict with the polars DataFrames
+     'overview' (`cases`, `events`, `activities`, `resources`),
+     'trace_lengths' (`length`, `cases`),
+     'case_durations' (`count`, `mean`, `median`, `min`, `max` in seconds),
+     'events_per_bucket' (`bucket`, `events`; only if the timestamp column is a datetime column) and
+     'attributes' (`column`, `dtype`, `cardinality`, `null_ratio`)
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+     * `timestamp_col` - Optional timestamp column (default: `"time:timestamp"`)
+     * `resource_col` - Optional resource column (default: `"org:resource"`)
+     * `time_bucket` - Optional size of the time buckets events are counted in: `"hour"`, `"day"` (default), `"week"`, `"month"` or `"year"`
+
+    """
+    return log_summary_rs(df, activity_col, case_col, timestamp_col, resource_col, time_bucket)
+
+
+def discover_alpha(df: polars.DataFrame, activity_col: Optional[str] = None, case_col: Optional[str] = None, alpha_plus: Optional[bool] = None) -> dict[str, Any]:
+    """
+     Discover a Petri net from an event log using the Alpha Miner
+
+     Returns the Petri net as dict with the keys 'places' (list of `{"id"}`), 'transitions' (list of `{"id", "label"}`),
+     'arcs' (list of `{"source", "target", "weight"}`), 'initial_marking' (dict of place id to tokens) and 'final_markings' (list of markings)
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+     * `alpha_plus` - Optional flag to use Alpha+, which additionally handles length-one and length-two loops (default: `False`)
+
+    """
+    return json.loads(discover_alpha_rs(df, activity_col, case_col, alpha_plus))
+
+
+def discover_inductive(df: polars.DataFrame, activity_col: Optional[str] = None, case_col: Optional[str] = None, noise_threshold: Optional[float] = None) -> dict[str, Any]:
+    """
+     Discover a process tree from an event log using the Inductive Miner (IM, or IMf with a positive noise threshold)
+
+     Returns the process tree as nested dict: leaves are `{"label": ...}` (with `None` for silent leaves),
+     inner nodes are `{"operator": ..., "children": [...]}` with the operators `"sequence"`, `"xor"`, `"parallel"` and `"loop"`
+     (the first child of a loop is the do part, the others are redo parts)
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+     * `noise_threshold` - Optional noise threshold between 0 and 1 for IMf (default: `0`, i.e., IM)
+
+    """
+    return json.loads(discover_inductive_rs(df, activity_col, case_col, noise_threshold))
+
+
+def process_tree_to_petri_net(tree: dict[str, Any]) -> dict[str, Any]:
+    """
+     Convert a process tree (as returned by `discover_inductive`) to a Petri net
+
+     Returns the Petri net as dict (with the same keys as returned by `discover_alpha`)
+
+     * `tree` - The process tree as nested dict
+
+    """
+    return json.loads(process_tree_to_petri_net_rs(json.dumps(tree)))
+
+
+def token_replay(df: polars.DataFrame, net: dict[str, Any], activity_col: Optional[str] = None, case_col: Optional[str] = None) -> tuple[polars.DataFrame, float]:
+    """
+     Token-based replay of an event log on a Petri net (computed once per variant, in parallel)
+
+     Returns a tuple of a polars DataFrame with one row per case (case id, `fitness`, `is_fit`, `missing`, `remaining`, `produced`, `consumed`
+     and `unknown_activities`, i.e., events without a transition in the net) and the log fitness
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `net` - The Petri net as dict (e.g., as returned by `discover_alpha` or `import_pnml`), which needs an initial marking
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+
+    """
+    return token_replay_rs(df, json.dumps(net), activity_col, case_col)
+
+
+def alignments(df: polars.DataFrame, net: dict[str, Any], activity_col: Optional[str] = None, case_col: Optional[str] = None) -> tuple[polars.DataFrame, polars.DataFrame, float, int]:
+    """
+     Optimal alignments of the cases of an event log with a Petri net (A* search, computed once per variant, in parallel)
+
+     Log moves and model moves on labeled transitions cost 1, synchronous moves and model moves on silent transitions are free.
+     The search is stopped after 1,000,000 explored states per variant: cases of such variants have no alignment (null values) and are excluded from the log fitness.
+
+     Returns a tuple of
+     * a polars DataFrame with one row per case (case id, `cost`, `fitness`, `is_fit`, `sync_moves`, `log_moves` and `model_moves`)
+     * a polars DataFrame with the moves of all alignments (case id, `step`, `move` (`"sync"`, `"log"` or `"model"`), `activity` and `transition`)
+     * the log fitness
+     * the number of cases without alignment
+
+     * `df` - The polars DataFrame representing the event log (e.g., as returned by `import_xes`)
+     * `net` - The Petri net as dict (e.g., as returned by `discover_alpha` or `import_pnml`), which needs an initial marking
+     * `activity_col` - Optional activity column (default: `"concept:name"`)
+     * `case_col` - Optional case id column (default: `"case:concept:name"`)
+
+    """
+    return alignments_rs(df, json.dumps(net), activity_col, case_col)
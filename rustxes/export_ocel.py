@@ -0,0 +1,15 @@
+from .rustxes import export_ocel_rs
+import polars
+
+
+def export_ocel(ocel: dict[str, polars.DataFrame], path: str):
+    """
+     Export an OCEL to an OCEL2 XML or JSON file
+
+     Type declarations are derived from the attribute values of the events/objects of each type.
+
+     * `ocel` - The OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`)
+     * `path` - The filepath where the .xml or .json file should be saved
+
+    """
+    return export_ocel_rs(ocel, path)
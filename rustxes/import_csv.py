@@ -0,0 +1,24 @@
+from typing import Optional
+from .rustxes import import_csv_log_rs
+import polars
+
+
+def import_csv_log(path: str, case_col: str, activity_col: str, timestamp_col: str, timestamp_format: Optional[str] = None, separator: Optional[str] = None, resource_col: Optional[str] = None, case_attribute_cols: Optional[list[str]] = None, timezone: Optional[str] = None, xes_path: Optional[str] = None) -> polars.DataFrame:
+    """
+     Import a case-centric event log from a CSV file (one row per event)
+
+     Returns a polars DataFrame with the same schema as `import_xes` (events sorted by timestamp within each case)
+
+     * `path` - The filepath of the .csv file to import
+     * `case_col` - Column containing the case id (becomes `case:concept:name`)
+     * `activity_col` - Column containing the activity (becomes `concept:name`)
+     * `timestamp_col` - Column containing the event timestamp (becomes `time:timestamp`)
+     * `timestamp_format` - Optional format of the timestamps (See https://docs.rs/chrono/latest/chrono/format/strftime/index.html); timestamps without offset are interpreted as UTC
+     * `separator` - Optional column separator (default: `","`)
+     * `resource_col` - Optional column containing the resource (becomes `org:resource`)
+     * `case_attribute_cols` - Optional list of columns containing case attributes (in addition to all columns starting with `case:`)
+     * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+     * `xes_path` - Optional filepath of a .xes or .xes.gz file the imported log should be exported to
+
+    """
+    return import_csv_log_rs(path, case_col, activity_col, timestamp_col, timestamp_format, separator, resource_col, case_attribute_cols, timezone, xes_path)
@@ -0,0 +1,16 @@
+from typing import Optional
+from .rustxes import import_mxml_rs
+import polars
+
+
+def import_mxml(path: str, timezone: Optional[str] = None) -> tuple[polars.DataFrame, str]:
+    """
+     Import an MXML event log
+
+     Returns a tuple of a Polars [DataFrame] with the same layout as `import_xes` and a json-encoding of all log attributes
+
+     * `path` - The filepath of the .mxml or .mxml.gz file to import
+     * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+
+    """
+    return import_mxml_rs(path, timezone)
@@ -1,3 +1,4 @@
+from typing import Optional
 from .rustxes import import_ocel_xml_rs, import_ocel_json_rs
 import polars
 
@@ -33,16 +34,27 @@ def rs_ocel_to_pm4py(ocel_rs: dict[str, polars.DataFrame]):
     return ocel_pm4py
 
 
-def import_ocel_xml(path: str) -> dict[str, polars.DataFrame]:
+def import_ocel_xml(path: str, object_changes_format: Optional[str] = None, initial_attributes: Optional[str] = None, strict: Optional[bool] = None, timezone: Optional[str] = None, time_unit: Optional[str] = None, out_of_range: Optional[str] = None, object_types: Optional[list[str]] = None, exclude_object_types: Optional[list[str]] = None, event_types: Optional[list[str]] = None, exclude_event_types: Optional[list[str]] = None, cache_dir: Optional[str] = None) -> dict[str, polars.DataFrame]:
     """
      Import an OCEL2 XML event log
 
      Returns an dict with the polars DataFrames for the following keys: 'objects', 'events', 'o2o', 'object_changes', 'relations'
 
-     * `path` - The filepath of the .xml file to import
+     * `path` - The filepath of the .xml (or OCEL 1.0 .xmlocel) file to import; OCEL 1.0 XML-OCEL files saved as .xml are detected as well
+     * `object_changes_format` - Optional layout of the 'object_changes' DataFrame: `"wide"` (default, one column per object attribute) or `"long"` (one row per attribute value with typed `ocel:value:*` columns)
+     * `initial_attributes` - Optional policy for the initial attribute values in the 'objects' DataFrame: `"epoch"` (only values at the Unix epoch), `"earliest"` (default, earliest recorded value) or `"first_event"` (value at the time of the first related event)
+     * `strict` - Optional flag to raise an error if the OCEL has referential integrity or schema issues (see `validate_ocel`)
+     * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+     * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+     * `out_of_range` - Optional handling of timestamps not representable with the time unit (e.g., `0001-01-01` with `"ns"`): `"error"` (default, raise an error naming the offending event/object), `"null"` or `"clamp"`
+     * `object_types` - Optional list of object types to keep; all other objects (and their relationships and attribute changes) are removed
+     * `exclude_object_types` - Optional list of object types to remove
+     * `event_types` - Optional list of event types to keep; all other events are removed
+     * `exclude_event_types` - Optional list of event types to remove
+     * `cache_dir` - Optional directory for caching the imported DataFrames as Parquet files; they are reloaded transparently as long as the file (path, modification time, size) and the import options are unchanged
 
     """
-    return import_ocel_xml_rs(path)
+    return import_ocel_xml_rs(path, object_changes_format, initial_attributes, strict, timezone, time_unit, out_of_range, object_types, exclude_object_types, event_types, exclude_event_types, cache_dir)
 
 
 def import_ocel_xml_pm4py(path: str):
@@ -52,22 +64,34 @@ def import_ocel_xml_pm4py(path: str):
      * `path` - The filepath of the .xml file to import
 
     """
-    ocel_rs = import_ocel_xml(path)
+    # PM4PY only considers attribute values at the Unix epoch as initial object attributes and uses timezone-naive timestamps
+    ocel_rs = import_ocel_xml(path, initial_attributes="epoch", timezone="naive")
     return rs_ocel_to_pm4py(ocel_rs)
 
 
 
 
-def import_ocel_json(path: str) -> dict[str, polars.DataFrame]:
+def import_ocel_json(path: str, object_changes_format: Optional[str] = None, initial_attributes: Optional[str] = None, strict: Optional[bool] = None, timezone: Optional[str] = None, time_unit: Optional[str] = None, out_of_range: Optional[str] = None, object_types: Optional[list[str]] = None, exclude_object_types: Optional[list[str]] = None, event_types: Optional[list[str]] = None, exclude_event_types: Optional[list[str]] = None, cache_dir: Optional[str] = None) -> dict[str, polars.DataFrame]:
     """
      Import an OCEL2 JSON event log
 
      Returns an dict with the polars DataFrames for the following keys: 'objects', 'events', 'o2o', 'object_changes', 'relations'
 
-     * `path` - The filepath of the .json file to import
+     * `path` - The filepath of the .json (or OCEL 1.0 .jsonocel) file to import
+     * `object_changes_format` - Optional layout of the 'object_changes' DataFrame: `"wide"` (default, one column per object attribute) or `"long"` (one row per attribute value with typed `ocel:value:*` columns)
+     * `initial_attributes` - Optional policy for the initial attribute values in the 'objects' DataFrame: `"epoch"` (only values at the Unix epoch), `"earliest"` (default, earliest recorded value) or `"first_event"` (value at the time of the first related event)
+     * `strict` - Optional flag to raise an error if the OCEL has referential integrity or schema issues (see `validate_ocel`)
+     * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+     * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+     * `out_of_range` - Optional handling of timestamps not representable with the time unit (e.g., `0001-01-01` with `"ns"`): `"error"` (default, raise an error naming the offending event/object), `"null"` or `"clamp"`
+     * `object_types` - Optional list of object types to keep; all other objects (and their relationships and attribute changes) are removed
+     * `exclude_object_types` - Optional list of object types to remove
+     * `event_types` - Optional list of event types to keep; all other events are removed
+     * `exclude_event_types` - Optional list of event types to remove
+     * `cache_dir` - Optional directory for caching the imported DataFrames as Parquet files; they are reloaded transparently as long as the file (path, modification time, size) and the import options are unchanged
 
     """
-    return import_ocel_json_rs(path)
+    return import_ocel_json_rs(path, object_changes_format, initial_attributes, strict, timezone, time_unit, out_of_range, object_types, exclude_object_types, event_types, exclude_event_types, cache_dir)
 
 
 def import_ocel_json_pm4py(path: str):
@@ -77,5 +101,6 @@ def import_ocel_json_pm4py(path: str):
      * `path` - The filepath of the .json file to import
 
     """
-    ocel_rs = import_ocel_json(path)
+    # PM4PY only considers attribute values at the Unix epoch as initial object attributes and uses timezone-naive timestamps
+    ocel_rs = import_ocel_json(path, initial_attributes="epoch", timezone="naive")
     return rs_ocel_to_pm4py(ocel_rs)
@@ -3,7 +3,7 @@ from .rustxes import import_xes_rs
 import polars
 
 
-def import_xes(path: str, date_format: Optional[str] = None, print_debug: Optional[bool] = None) -> tuple[polars.DataFrame, str]:
+def import_xes(path: str, date_format: Optional[str] = None, print_debug: Optional[bool] = None, timezone: Optional[str] = None, cache_dir: Optional[str] = None) -> tuple[polars.DataFrame, str]:
     """
      Import an XES event log
 
@@ -12,6 +12,8 @@ def import_xes(path: str, date_format: Optional[str] = None, print_debug: Option
      * `path` - The filepath of the .xes or .xes.gz file to import
      * `date_format` - Optional date format to use for parsing `<date>` tags (See https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
      * `print_debug` - Optional flag to enable debug print outputs
+     * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+     * `cache_dir` - Optional directory for caching the imported log as Parquet file; it is reloaded transparently as long as the file (path, modification time, size) and the import options are unchanged
 
     """
-    return import_xes_rs(path, date_format, print_debug)
+    return import_xes_rs(path, date_format, print_debug, timezone, cache_dir)
@@ -0,0 +1,154 @@
+from datetime import datetime
+import json
+from typing import Optional, Union
+from .rustxes import object_snapshot_rs, enrich_events_with_object_state_rs, validate_ocel_path_rs, validate_ocel_dfs_rs, slice_ocel_rs, flatten_ocel_rs, xes_to_ocel_rs, discover_ocdfg_rs, ocel_summary_rs
+import polars
+
+
+def _timestamp_to_str(timestamp: Union[datetime, str]) -> str:
+    return timestamp.isoformat() if isinstance(timestamp, datetime) else timestamp
+
+
+def object_snapshot(ocel: dict[str, polars.DataFrame], at: Union[datetime, str], time_unit: Optional[str] = None, out_of_range: Optional[str] = None, object_types: Optional[dict[str, dict[str, str]]] = None) -> polars.DataFrame:
+    """
+     Compute the state of all objects of an OCEL at a given point in time
+
+     Returns a polars DataFrame with one row per object (`ocel:oid`, `ocel:type`) and, for every object attribute,
+     the latest value recorded at or before `at`
+
+     * `ocel` - The OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`)
+     * `at` - The point in time (`datetime` or ISO 8601 string; timestamps without offset are interpreted as UTC)
+     * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+     * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+     * `object_types` - Optional declared object types (object type -> attribute name -> attribute type, e.g., `"string"`, `"time"`, `"integer"`,
+       `"float"` or `"boolean"`); without them, the attribute types are derived from the DataFrames
+
+    """
+    return object_snapshot_rs(ocel, _timestamp_to_str(at), time_unit, out_of_range, object_types)
+
+
+def enrich_events_with_object_state(ocel: dict[str, polars.DataFrame], time_unit: Optional[str] = None, out_of_range: Optional[str] = None, object_types: Optional[dict[str, dict[str, str]]] = None) -> polars.DataFrame:
+    """
+     Enrich all E2O relations of an OCEL with the state of the related objects at the time of the event
+
+     Returns a polars DataFrame with the columns of the 'relations' DataFrame and, for every object attribute,
+     the latest value of the related object recorded at or before the event timestamp
+
+     * `ocel` - The OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`)
+     * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+     * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+     * `object_types` - Optional declared object types (object type -> attribute name -> attribute type, e.g., `"string"`, `"time"`, `"integer"`,
+       `"float"` or `"boolean"`); without them, the attribute types are derived from the DataFrames
+
+    """
+    return enrich_events_with_object_state_rs(ocel, time_unit, out_of_range, object_types)
+
+
+def validate_ocel(ocel: Union[str, dict[str, polars.DataFrame]], event_types: Optional[dict[str, dict[str, str]]] = None, object_types: Optional[dict[str, dict[str, str]]] = None) -> dict:
+    """
+     Check an OCEL for referential integrity and schema issues
+
+     Returns a dict with a list of issues for each of the following keys: 'dangling_e2o', 'dangling_o2o', 'duplicate_event_ids',
+     'duplicate_object_ids', 'events_without_objects', 'undeclared_event_types', 'undeclared_object_types',
+     'undeclared_event_attributes', 'undeclared_object_attributes', 'attribute_type_mismatches'
+
+     * `ocel` - The filepath of an OCEL2 .xml or .json file, or an OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`).
+       Note, that for DataFrames without `event_types`/`object_types`, the type declarations are derived from the data,
+       so no undeclared types, undeclared attributes or type mismatches are reported.
+     * `event_types` - Optional declared event types for DataFrame input (event type -> attribute name -> attribute type, e.g., `"string"`, `"time"`, `"integer"`, `"float"` or `"boolean"`)
+     * `object_types` - Optional declared object types for DataFrame input (object type -> attribute name -> attribute type)
+
+    """
+    if isinstance(ocel, str):
+        return json.loads(validate_ocel_path_rs(ocel))
+    return json.loads(validate_ocel_dfs_rs(ocel, event_types, object_types))
+
+
+def slice_ocel(ocel: dict[str, polars.DataFrame], start: Union[datetime, str], end: Union[datetime, str], object_policy: Optional[str] = None, object_changes_format: Optional[str] = None, initial_attributes: Optional[str] = None, timezone: Optional[str] = None, time_unit: Optional[str] = None, out_of_range: Optional[str] = None) -> dict[str, polars.DataFrame]:
+    """
+     Slice an OCEL to the time window from `start` (inclusive) to `end` (exclusive)
+
+     Returns the sliced OCEL as dict of polars DataFrames (with the same keys as `import_ocel_xml`/`import_ocel_json`).
+     E2O/O2O relationships to removed objects are dropped (events which lose all their related objects are removed)
+     and object attribute changes recorded at or after `end` are removed.
+
+     * `ocel` - The OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`)
+     * `start` - Start of the time window (`datetime` or ISO 8601 string)
+     * `end` - End of the time window (`datetime` or ISO 8601 string)
+     * `object_policy` - Optional policy for the kept objects: `"related"` (default, objects related to events in the window and their O2O-related objects),
+       `"touched"` (only objects related to events in the window) or `"contained"` (only objects whose events all lie in the window)
+     * `object_changes_format` - Optional layout of the 'object_changes' DataFrame: `"wide"` or `"long"` (default: layout of the input)
+     * `initial_attributes` - Optional policy for the initial attribute values in the 'objects' DataFrame: `"epoch"`, `"earliest"` (default) or `"first_event"`
+     * `timezone` - Optional timezone handling for datetime columns: `"utc"`, `"naive"` or a named timezone (default: timezone of the input)
+     * `time_unit` - Optional time unit of datetime columns: `"ns"`, `"us"` or `"ms"` (default: time unit of the input)
+     * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+
+    """
+    return slice_ocel_rs(ocel, _timestamp_to_str(start), _timestamp_to_str(end), object_policy, object_changes_format, initial_attributes, timezone, time_unit, out_of_range)
+
+
+def flatten_ocel(ocel: dict[str, polars.DataFrame], object_type: str, path: Optional[str] = None) -> polars.DataFrame:
+    """
+     Flatten an OCEL on an object type, resulting in a case-centric event log
+
+     Returns a polars DataFrame with the same schema as `import_xes`: Every object of the given type becomes a case
+     (`case:concept:name` is the object id and the initial object attribute values are trace attributes),
+     containing all events related to the object
+
+     * `ocel` - The OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`)
+     * `object_type` - The object type to flatten on
+     * `path` - Optional filepath of a .xes or .xes.gz file the flattened log should be exported to
+
+    """
+    return flatten_ocel_rs(ocel, object_type, path)
+
+
+def xes_to_ocel(df: polars.DataFrame, object_columns: list[str], case_object_type: Optional[str] = None) -> dict[str, polars.DataFrame]:
+    """
+     Convert a case-centric event log to an OCEL
+
+     Returns the OCEL as dict of polars DataFrames (with the same keys as `import_ocel_xml`/`import_ocel_json`).
+     Every case becomes an object (with the trace attributes as object attributes) and the values of the `object_columns`
+     become objects of the type named after the column, related to the events with the column name as qualifier.
+     Object ids are prefixed with their type (e.g., `case:1` or `org:resource:Mike`).
+     Raises a `ValueError` if an event has no `time:timestamp`.
+
+     * `df` - The polars DataFrame representation of the event log (e.g., as returned by `import_xes`)
+     * `object_columns` - Event attribute columns whose values become objects (e.g., `["org:resource", "order_id"]`)
+     * `case_object_type` - Optional object type of the case objects (default: `"case"`)
+
+    """
+    return xes_to_ocel_rs(df, object_columns, case_object_type)
+
+
+def discover_ocdfg(ocel: dict[str, polars.DataFrame]) -> dict[str, polars.DataFrame]:
+    """
+     Discover the object-centric directly-follows graph of an OCEL
+
+     For every object type, the events of each object (ordered by timestamp) induce directly-follows edges between their event types.
+
+     Returns a dict with the polars DataFrames 'nodes' (`ocel:type`, `ocel:activity`, `events`, `unique_objects`, `total_objects`, `start_objects`, `end_objects`)
+     and 'edges' (`ocel:type`, `source`, `target`, `events`, `unique_objects`, `total_objects`)
+
+     * `ocel` - The OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`)
+
+    """
+    return discover_ocdfg_rs(ocel)
+
+
+def ocel_summary(ocel: dict[str, polars.DataFrame]) -> dict[str, polars.DataFrame]:
+    """
+     Summarize an OCEL
+
+     Returns a dict with the polars DataFrames
+     'overview' (`events`, `objects`, `event_types`, `object_types`, `e2o`, `o2o`),
+     'event_types' (`ocel:activity`, `events`),
+     'object_types' (`ocel:type`, `objects`),
+     'e2o_degrees' (`ocel:type`, `events`, `objects`): number of objects of each type related to the given number of events,
+     'o2o_degrees' (`ocel:type`, `related_objects`, `objects`): number of objects of each type related to the given number of other objects and
+     'objects_per_event' (`ocel:activity`, `objects`, `events`): number of events of each type related to the given number of objects
+
+     * `ocel` - The OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`)
+
+    """
+    return ocel_summary_rs(ocel)
@@ -0,0 +1,34 @@
+from typing import Optional, Union
+from .rustxes import write_ocel_parquet_path_rs, write_ocel_parquet_dfs_rs, read_ocel_parquet_rs
+import polars
+
+
+def write_ocel_parquet(ocel: Union[str, dict[str, polars.DataFrame]], path: str, event_types: Optional[dict[str, dict[str, str]]] = None, object_types: Optional[dict[str, dict[str, str]]] = None):
+    """
+     Write an OCEL as Parquet files (one per DataFrame plus `types.parquet` with the type declarations) to a directory
+
+     Only directories are supported as target (not single files).
+
+     * `ocel` - The filepath of an OCEL2 .xml or .json file, or an OCEL as dict of polars DataFrames (as returned by `import_ocel_xml`/`import_ocel_json`).
+       Note, that for DataFrames the type declarations are derived from the data, unless `event_types`/`object_types` are given.
+     * `path` - The directory the Parquet files should be written to
+     * `event_types` - Optional declared event types for DataFrames (event type -> attribute name -> attribute type, e.g., `"string"`, `"time"`, `"integer"`, `"float"` or `"boolean"`)
+     * `object_types` - Optional declared object types for DataFrames (object type -> attribute name -> attribute type)
+
+    """
+    if isinstance(ocel, str):
+        return write_ocel_parquet_path_rs(ocel, path)
+    return write_ocel_parquet_dfs_rs(ocel, path, event_types, object_types)
+
+
+def read_ocel_parquet(path: str, export_path: Optional[str] = None) -> dict[str, polars.DataFrame]:
+    """
+     Read an OCEL from Parquet files written by `write_ocel_parquet`
+
+     Returns an dict with the polars DataFrames for the following keys: 'objects', 'events', 'o2o', 'object_changes', 'relations'
+
+     * `path` - The directory containing the Parquet files
+     * `export_path` - Optional filepath of a .xml or .json file the OCEL (including its stored type declarations) should be exported to
+
+    """
+    return read_ocel_parquet_rs(path, export_path)
@@ -0,0 +1,26 @@
+import json
+from typing import Any
+from .rustxes import import_pnml_rs, export_pnml_rs
+
+
+def import_pnml(path: str) -> dict[str, Any]:
+    """
+     Import a Petri net from a PNML file
+
+     Returns the Petri net as dict (with the same keys as returned by `discover_alpha`)
+
+     * `path` - The filepath of the .pnml file to import
+
+    """
+    return json.loads(import_pnml_rs(path))
+
+
+def export_pnml(net: dict[str, Any], path: str):
+    """
+     Export a Petri net to a PNML file
+
+     * `net` - The Petri net as dict (e.g., as returned by `discover_alpha` or `import_pnml`)
+     * `path` - The filepath where the .pnml file should be saved
+
+    """
+    return export_pnml_rs(json.dumps(net), path)
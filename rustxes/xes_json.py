@@ -0,0 +1,28 @@
+from typing import Optional
+from .rustxes import import_xes_json_rs, export_xes_json_rs
+import polars
+
+
+def import_xes_json(path: str, timezone: Optional[str] = None) -> tuple[polars.DataFrame, str]:
+    """
+     Import an XES event log from its JSON encoding
+
+     Returns a tuple of a Polars [DataFrame] for the event data and a json-encoding of all log attributes/extensions/classifiers (as `import_xes`)
+
+     * `path` - The filepath of the .json or .json.gz file to import
+     * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+
+    """
+    return import_xes_json_rs(path, timezone)
+
+
+def export_xes_json(df: polars.DataFrame, path: str, log_data: Optional[str] = None):
+    """
+     Export an XES event log to its JSON encoding
+
+     * `df` - The Polars DataFrame representation of the event log to export
+     * `path` - The filepath where the .json or .json.gz file should be saved
+     * `log_data` - Optional json-encoding of the log attributes/extensions/classifiers (as returned by `import_xes`)
+
+    """
+    return export_xes_json_rs(df, path, log_data)
@@ -0,0 +1,391 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use polars::prelude::*;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult, Python};
+use pyo3_polars::PyDataFrame;
+use rayon::prelude::*;
+
+use crate::petri_net::{petri_net_from_py, IndexedPetriNet};
+
+use super::{case_log_from_df, variants::variants, CaseLog, EventColumns};
+
+/// Cost of a log move or a model move on a labeled transition
+const DEVIATION_COST: u64 = 10_000;
+/// Cost of a model move on a silent transition (small, so that alignments avoid unnecessary silent moves)
+const SILENT_COST: u64 = 1;
+/// Maximal number of states explored by the search for a single alignment
+const MAX_ALIGNMENT_STATES: usize = 1_000_000;
+
+/// Move of an alignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignmentMove {
+    /// Synchronous move of an event (activity id) and a transition
+    Sync(usize, usize),
+    /// Move on log only (activity id)
+    Log(usize),
+    /// Move on model only (transition)
+    Model(usize),
+}
+
+/// Optimal alignment of a trace and a Petri net
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alignment {
+    pub moves: Vec<AlignmentMove>,
+    /// Number of log moves and model moves on labeled transitions
+    pub cost: u64,
+    /// Cost of the worst alignment (all events as log moves, followed by an optimal alignment of the empty trace)
+    pub worst_cost: u64,
+}
+
+impl Alignment {
+    /// Trace fitness `1 - cost / worst_cost`
+    pub fn fitness(&self) -> f64 {
+        if self.worst_cost == 0 {
+            1.0
+        } else {
+            1.0 - self.cost as f64 / self.worst_cost as f64
+        }
+    }
+
+    fn count(&self, f: fn(&AlignmentMove) -> bool) -> u64 {
+        self.moves.iter().filter(|&m| f(m)).count() as u64
+    }
+}
+
+/// Node of the search over the synchronous product
+struct SearchNode {
+    marking: Vec<u64>,
+    position: usize,
+    cost: u64,
+    parent: Option<(usize, AlignmentMove)>,
+}
+
+/// Computes optimal alignments of traces (as activity ids of a [CaseLog]) with an [IndexedPetriNet]
+pub struct Aligner<'a> {
+    net: &'a IndexedPetriNet,
+    /// Transitions labeled with each activity (by activity id)
+    activity_transitions: Vec<Vec<usize>>,
+    /// Cost of aligning the empty trace (number of model moves on labeled transitions)
+    empty_trace_cost: Option<u64>,
+}
+
+impl<'a> Aligner<'a> {
+    pub fn new(net: &'a IndexedPetriNet, activities: &[String]) -> Self {
+        let activity_transitions = activities
+            .iter()
+            .map(|a| {
+                (0..net.transitions.len())
+                    .filter(|t| net.transitions[*t].label.as_ref() == Some(a))
+                    .collect()
+            })
+            .collect();
+        let mut aligner = Self {
+            net,
+            activity_transitions,
+            empty_trace_cost: None,
+        };
+        aligner.empty_trace_cost = aligner.search(&[]).map(|(cost, _)| cost / DEVIATION_COST);
+        aligner
+    }
+
+    fn move_cost(&self, step: &AlignmentMove) -> u64 {
+        match step {
+            AlignmentMove::Sync(_, _) => 0,
+            AlignmentMove::Log(_) => DEVIATION_COST,
+            AlignmentMove::Model(t) if self.net.transitions[*t].label.is_none() => SILENT_COST,
+            AlignmentMove::Model(_) => DEVIATION_COST,
+        }
+    }
+
+    ///
+    /// Transitions that may still become enabled from a marking
+    ///
+    /// Token counts are ignored (every place that is marked or can be marked by such a transition counts as marked),
+    /// so this over-approximates the transitions that can fire in any continuation.
+    ///
+    fn reachable_transitions(&self, marking: &[u64]) -> Vec<bool> {
+        let mut marked: Vec<bool> = marking.iter().map(|tokens| *tokens > 0).collect();
+        let mut reachable = vec![false; self.net.transitions.len()];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (t, transition) in self.net.transitions.iter().enumerate() {
+                if !reachable[t] && transition.inputs.iter().all(|(p, _)| marked[*p]) {
+                    reachable[t] = true;
+                    changed = true;
+                    for (p, _) in &transition.outputs {
+                        marked[*p] = true;
+                    }
+                }
+            }
+        }
+        reachable
+    }
+
+    ///
+    /// Lower bound of the remaining cost
+    ///
+    /// Remaining events whose activity has no transition that can still become enabled from the marking
+    /// (see [Aligner::reachable_transitions]) can only be log moves.
+    ///
+    fn heuristic(&self, trace: &[usize], position: usize, marking: &[u64]) -> u64 {
+        let remaining = &trace[position..];
+        if remaining.is_empty() {
+            return 0;
+        }
+        let reachable = self.reachable_transitions(marking);
+        remaining
+            .iter()
+            .filter(|a| !self.activity_transitions[**a].iter().any(|t| reachable[*t]))
+            .count() as u64
+            * DEVIATION_COST
+    }
+
+    ///
+    /// A* search over the synchronous product of the trace and the net
+    ///
+    /// Returns the total (internal) cost and moves of an optimal alignment, or `None` if the final marking cannot be reached
+    /// (within [MAX_ALIGNMENT_STATES] explored states)
+    ///
+    fn search(&self, trace: &[usize]) -> Option<(u64, Vec<AlignmentMove>)> {
+        let net = self.net;
+        let mut nodes = vec![SearchNode {
+            marking: net.initial_marking.clone(),
+            position: 0,
+            cost: 0,
+            parent: None,
+        }];
+        let mut best_costs: HashMap<(Vec<u64>, usize), u64> =
+            HashMap::from([((net.initial_marking.clone(), 0), 0)]);
+        // Ordered by estimated total cost, preferring states further in the trace
+        let mut queue = BinaryHeap::from([Reverse((
+            self.heuristic(trace, 0, &net.initial_marking),
+            Reverse(0),
+            0,
+        ))]);
+        let mut expanded = 0;
+        while let Some(Reverse((_, _, node_index))) = queue.pop() {
+            let node = &nodes[node_index];
+            let key = (node.marking.clone(), node.position);
+            if best_costs.get(&key).is_some_and(|c| *c < node.cost) {
+                continue;
+            }
+            if node.position == trace.len() && node.marking == net.final_marking {
+                let cost = node.cost;
+                let mut moves = Vec::new();
+                let mut current = node_index;
+                while let Some((parent, step)) = nodes[current].parent {
+                    moves.push(step);
+                    current = parent;
+                }
+                moves.reverse();
+                return Some((cost, moves));
+            }
+            expanded += 1;
+            if expanded > MAX_ALIGNMENT_STATES {
+                return None;
+            }
+
+            let mut successors: Vec<(Vec<u64>, usize, AlignmentMove)> = Vec::new();
+            if let Some(activity) = trace.get(node.position) {
+                successors.push((node.marking.clone(), node.position + 1, AlignmentMove::Log(*activity)));
+                for t in &self.activity_transitions[*activity] {
+                    if net.is_enabled(*t, &node.marking) {
+                        let mut marking = node.marking.clone();
+                        net.fire(*t, &mut marking);
+                        successors.push((marking, node.position + 1, AlignmentMove::Sync(*activity, *t)));
+                    }
+                }
+            }
+            for t in (0..net.transitions.len()).filter(|t| net.is_enabled(*t, &node.marking)) {
+                let mut marking = node.marking.clone();
+                net.fire(t, &mut marking);
+                successors.push((marking, node.position, AlignmentMove::Model(t)));
+            }
+
+            let cost = node.cost;
+            for (marking, position, step) in successors {
+                let next_cost = cost + self.move_cost(&step);
+                let key = (marking, position);
+                if best_costs.get(&key).is_some_and(|c| *c <= next_cost) {
+                    continue;
+                }
+                best_costs.insert(key.clone(), next_cost);
+                let estimate = next_cost + self.heuristic(trace, position, &key.0);
+                nodes.push(SearchNode {
+                    marking: key.0,
+                    position,
+                    cost: next_cost,
+                    parent: Some((node_index, step)),
+                });
+                queue.push(Reverse((estimate, Reverse(position), nodes.len() - 1)));
+            }
+        }
+        None
+    }
+
+    /// Optimal alignment of a trace (or `None` if the final marking cannot be reached)
+    pub fn align(&self, trace: &[usize]) -> Option<Alignment> {
+        let (_, moves) = self.search(trace)?;
+        let cost = moves
+            .iter()
+            .map(|m| self.move_cost(m))
+            .filter(|c| *c == DEVIATION_COST)
+            .count() as u64;
+        Some(Alignment {
+            moves,
+            cost,
+            worst_cost: trace.len() as u64 + self.empty_trace_cost.unwrap_or_default(),
+        })
+    }
+}
+
+///
+/// Compute optimal alignments of all cases of a [CaseLog] with an [IndexedPetriNet] (once per variant, in parallel)
+///
+/// Returns the alignments in the order of the cases of the log (`None` if the final marking cannot be reached)
+///
+pub fn align_log(log: &CaseLog, net: &IndexedPetriNet) -> Vec<Option<Alignment>> {
+    let aligner = Aligner::new(net, &log.activities);
+    let variants = variants(log);
+    let variant_alignments: Vec<Option<Alignment>> = variants
+        .par_iter()
+        .map(|v| aligner.align(&v.activities))
+        .collect();
+    let mut alignments = vec![None; log.cases.len()];
+    for (variant, alignment) in variants.iter().zip(variant_alignments) {
+        for case in &variant.cases {
+            alignments[*case] = alignment.clone();
+        }
+    }
+    alignments
+}
+
+///
+/// Log fitness `1 - sum of costs / sum of worst costs` over the alignments of all cases (cases without alignment are ignored)
+///
+pub fn alignment_log_fitness(alignments: &[Option<Alignment>]) -> f64 {
+    let (cost, worst_cost) = alignments
+        .iter()
+        .flatten()
+        .fold((0, 0), |(c, w), a| (c + a.cost, w + a.worst_cost));
+    if worst_cost == 0 {
+        1.0
+    } else {
+        1.0 - cost as f64 / worst_cost as f64
+    }
+}
+
+///
+/// Convert alignments to a cases [DataFrame] (`case_col`, `cost`, `fitness`, `is_fit`, `sync_moves`, `log_moves`, `model_moves`;
+/// null values for cases without alignment) and a moves [DataFrame] (`case_col`, `step`, `move`, `activity`, `transition`), where
+/// `move` is `sync`, `log` or `model`, `activity` the activity of the event (null for model moves) and `transition` the label of the
+/// transition (null for log moves and silent transitions)
+///
+pub fn alignments_to_dfs(
+    log: &CaseLog,
+    net: &IndexedPetriNet,
+    alignments: &[Option<Alignment>],
+    case_col: &str,
+) -> PolarsResult<(DataFrame, DataFrame)> {
+    let count = |f: fn(&AlignmentMove) -> bool| {
+        alignments
+            .iter()
+            .map(|a| a.as_ref().map(|a| a.count(f)))
+            .collect::<Vec<_>>()
+    };
+    let cases_df = DataFrame::new(vec![
+        Column::new(
+            case_col.into(),
+            log.cases.iter().map(|c| c.case_id.as_str()).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "cost".into(),
+            alignments.iter().map(|a| a.as_ref().map(|a| a.cost)).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "fitness".into(),
+            alignments.iter().map(|a| a.as_ref().map(Alignment::fitness)).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "is_fit".into(),
+            alignments.iter().map(|a| a.as_ref().map(|a| a.cost == 0)).collect::<Vec<_>>(),
+        ),
+        Column::new("sync_moves".into(), count(|m| matches!(m, AlignmentMove::Sync(_, _)))),
+        Column::new("log_moves".into(), count(|m| matches!(m, AlignmentMove::Log(_)))),
+        Column::new("model_moves".into(), count(|m| matches!(m, AlignmentMove::Model(_)))),
+    ])?;
+
+    let mut case_ids: Vec<&str> = Vec::new();
+    let mut steps: Vec<u64> = Vec::new();
+    let mut move_types: Vec<&str> = Vec::new();
+    let mut activities: Vec<Option<&str>> = Vec::new();
+    let mut transitions: Vec<Option<&str>> = Vec::new();
+    for (case, alignment) in log.cases.iter().zip(alignments) {
+        for (step, m) in alignment.iter().flat_map(|a| a.moves.iter()).enumerate() {
+            let (move_type, activity, transition) = match m {
+                AlignmentMove::Sync(a, t) => ("sync", Some(*a), Some(*t)),
+                AlignmentMove::Log(a) => ("log", Some(*a), None),
+                AlignmentMove::Model(t) => ("model", None, Some(*t)),
+            };
+            case_ids.push(&case.case_id);
+            steps.push(step as u64);
+            move_types.push(move_type);
+            activities.push(activity.map(|a| log.activities[a].as_str()));
+            transitions.push(transition.and_then(|t| net.transitions[t].label.as_deref()));
+        }
+    }
+    let moves_df = DataFrame::new(vec![
+        Column::new(case_col.into(), case_ids),
+        Column::new("step".into(), steps),
+        Column::new("move".into(), move_types),
+        Column::new("activity".into(), activities),
+        Column::new("transition".into(), transitions),
+    ])?;
+    Ok((cases_df, moves_df))
+}
+
+///
+/// Compute optimal alignments of the cases of an event log DataFrame with a Petri net (A* over the synchronous product, once per variant, in parallel)
+///
+/// Log moves and model moves on labeled transitions cost 1, synchronous moves and model moves on silent transitions are free.
+///
+/// The search is stopped after 1,000,000 explored states per variant: cases of such variants have no alignment
+/// (null values in the cases DataFrame) and are excluded from the log fitness.
+///
+/// Returns a tuple of a cases DataFrame (case id, `cost`, `fitness`, `is_fit`, `sync_moves`, `log_moves`, `model_moves`),
+/// a moves DataFrame (case id, `step`, `move`, `activity`, `transition`), the log fitness and the number of cases without alignment
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `net` - The json-encoding of the Petri net (e.g., as returned by `discover_alpha_rs` or `import_pnml_rs`)
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+///
+#[pyfunction]
+#[pyo3(signature = (df, net, activity_col=None, case_col=None))]
+pub fn alignments_rs(
+    py: Python<'_>,
+    df: PyDataFrame,
+    net: String,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+) -> PyResult<(PyDataFrame, PyDataFrame, f64, usize)> {
+    let net = IndexedPetriNet::new(&petri_net_from_py(&net)?).map_err(PyValueError::new_err)?;
+    let columns = EventColumns::new(case_col, activity_col, None);
+    py.allow_threads(|| {
+        let log = case_log_from_df(&df.0, &columns)
+            .map_err(|e| PyValueError::new_err(format!("Failed to read event log: {e:?}")))?;
+        let alignments = align_log(&log, &net);
+        let (cases_df, moves_df) = alignments_to_dfs(&log, &net, &alignments, &columns.case_col)
+            .map_err(|e| PyValueError::new_err(format!("Failed to create DataFrames: {e:?}")))?;
+        Ok((
+            PyDataFrame(cases_df),
+            PyDataFrame(moves_df),
+            alignment_log_fitness(&alignments),
+            alignments.iter().filter(|a| a.is_none()).count(),
+        ))
+    })
+}
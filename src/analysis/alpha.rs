@@ -0,0 +1,277 @@
+use std::collections::{BTreeSet, HashSet};
+
+use process_mining::{
+    petri_net::petri_net_struct::{ArcType, Marking, PlaceID, TransitionID},
+    PetriNet,
+};
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::petri_net::petri_net_to_py;
+
+use super::{case_log_from_df, variants::variants, CaseLog, EventColumns};
+
+/// Ordering relations between activities derived from the directly-follows relation of a log
+struct Footprint {
+    num_activities: usize,
+    follows: Vec<bool>,
+    /// `triangle[a][b]` if some trace contains `a b a` (only used by Alpha+)
+    triangle: Option<Vec<bool>>,
+}
+
+impl Footprint {
+    fn new(num_activities: usize, traces: &[Vec<usize>], plus: bool) -> Self {
+        let mut follows = vec![false; num_activities * num_activities];
+        let mut triangle = plus.then(|| vec![false; num_activities * num_activities]);
+        for trace in traces {
+            for pair in trace.windows(2) {
+                follows[pair[0] * num_activities + pair[1]] = true;
+            }
+            if let Some(triangle) = triangle.as_mut() {
+                for t in trace.windows(3) {
+                    if t[0] == t[2] {
+                        triangle[t[0] * num_activities + t[1]] = true;
+                    }
+                }
+            }
+        }
+        Self {
+            num_activities,
+            follows,
+            triangle,
+        }
+    }
+
+    fn follows(&self, a: usize, b: usize) -> bool {
+        self.follows[a * self.num_activities + b]
+    }
+
+    /// `a ◇ b`: some traces contain `a b a` and some contain `b a b` (only used by Alpha+)
+    fn in_triangle(&self, a: usize, b: usize) -> bool {
+        self.triangle.as_ref().is_some_and(|triangle| {
+            triangle[a * self.num_activities + b] && triangle[b * self.num_activities + a]
+        })
+    }
+
+    /// `a -> b`: `b` directly follows `a`, but not vice versa (unless `a` and `b` form a length-two loop for Alpha+)
+    fn causal(&self, a: usize, b: usize) -> bool {
+        self.follows(a, b) && (!self.follows(b, a) || self.in_triangle(a, b))
+    }
+
+    /// `a # b`: neither `a` directly follows `b` nor vice versa
+    fn choice(&self, a: usize, b: usize) -> bool {
+        !self.follows(a, b) && !self.follows(b, a)
+    }
+
+    fn is_place(&self, inputs: &BTreeSet<usize>, outputs: &BTreeSet<usize>) -> bool {
+        let all_choice = |set: &BTreeSet<usize>| {
+            set.iter().all(|a| set.iter().all(|b| self.choice(*a, *b)))
+        };
+        all_choice(inputs)
+            && all_choice(outputs)
+            && inputs
+                .iter()
+                .all(|a| outputs.iter().all(|b| self.causal(*a, *b)))
+    }
+}
+
+/// Input and output activities of a place
+type AlphaPlace = (BTreeSet<usize>, BTreeSet<usize>);
+
+/// Maximal pairs of activity sets `(A, B)` with `a -> b` for all `a` in `A` and `b` in `B`, and `#` within `A` and within `B`
+fn maximal_places(footprint: &Footprint, activities: &[usize]) -> Vec<AlphaPlace> {
+    let mut candidates: Vec<AlphaPlace> = Vec::new();
+    for a in activities {
+        for b in activities {
+            let place = (BTreeSet::from([*a]), BTreeSet::from([*b]));
+            if footprint.is_place(&place.0, &place.1) {
+                candidates.push(place);
+            }
+        }
+    }
+    let mut seen: HashSet<AlphaPlace> = candidates.iter().cloned().collect();
+    let mut i = 0;
+    while i < candidates.len() {
+        for j in 0..i {
+            let inputs: BTreeSet<usize> = candidates[i].0.union(&candidates[j].0).copied().collect();
+            let outputs: BTreeSet<usize> =
+                candidates[i].1.union(&candidates[j].1).copied().collect();
+            if footprint.is_place(&inputs, &outputs) && seen.insert((inputs.clone(), outputs.clone()))
+            {
+                candidates.push((inputs, outputs));
+            }
+        }
+        i += 1;
+    }
+    let mut places: Vec<AlphaPlace> = candidates
+        .iter()
+        .filter(|(a, b)| {
+            !candidates
+                .iter()
+                .any(|(a2, b2)| (a != a2 || b != b2) && a.is_subset(a2) && b.is_subset(b2))
+        })
+        .cloned()
+        .collect();
+    places.sort();
+    places
+}
+
+///
+/// Discover a [PetriNet] using the Alpha Miner (or Alpha+ if `plus` is set)
+///
+/// Alpha+ additionally handles length-one loops (removed before discovery and re-inserted as self-loops on all places
+/// whose input and output activities contain their predecessors and successors, or on a new place between them if there
+/// is no such place) and length-two loops
+/// (`a` and `b` are not considered parallel if there are both `a b a` and `b a b` patterns).
+///
+/// The net has one transition per activity, a source place (marked in the initial marking) before all start activities
+/// and a sink place (marked in the final marking) after all end activities.
+///
+pub fn discover_alpha(log: &CaseLog, plus: bool) -> PetriNet {
+    let num_activities = log.activities.len();
+    let traces: Vec<Vec<usize>> = variants(log).into_iter().map(|v| v.activities).collect();
+    let mut loops = vec![false; num_activities];
+    if plus {
+        for trace in &traces {
+            for pair in trace.windows(2) {
+                if pair[0] == pair[1] {
+                    loops[pair[0]] = true;
+                }
+            }
+        }
+    }
+    let reduced: Vec<Vec<usize>> = traces
+        .iter()
+        .map(|t| t.iter().copied().filter(|a| !loops[*a]).collect())
+        .collect();
+    let footprint = Footprint::new(num_activities, &reduced, plus);
+    let activities: Vec<usize> = (0..num_activities).filter(|a| !loops[*a]).collect();
+
+    // Source and sink are represented by the additional activity ids `start` and `end`
+    let start = num_activities;
+    let end = num_activities + 1;
+    let start_activities: BTreeSet<usize> = reduced.iter().filter_map(|t| t.first().copied()).collect();
+    let end_activities: BTreeSet<usize> = reduced.iter().filter_map(|t| t.last().copied()).collect();
+    let mut places = vec![
+        (BTreeSet::from([start]), start_activities),
+        (end_activities, BTreeSet::from([end])),
+    ];
+    places.extend(maximal_places(&footprint, &activities));
+
+    let mut net = PetriNet::new();
+    let transitions: Vec<TransitionID> = log
+        .activities
+        .iter()
+        .map(|a| net.add_transition(Some(a.clone()), None))
+        .collect();
+    let place_ids: Vec<PlaceID> = places.iter().map(|_| net.add_place(None)).collect();
+    for ((inputs, outputs), place) in places.iter().zip(&place_ids) {
+        for a in inputs.iter().filter(|a| **a < num_activities) {
+            net.add_arc(ArcType::transition_to_place(transitions[*a], *place), None);
+        }
+        for b in outputs.iter().filter(|b| **b < num_activities) {
+            net.add_arc(ArcType::place_to_transition(*place, transitions[*b]), None);
+        }
+    }
+
+    for t in (0..num_activities).filter(|t| loops[*t]) {
+        let mut predecessors = BTreeSet::new();
+        let mut successors = BTreeSet::new();
+        for trace in &traces {
+            let reduced_trace: Vec<(usize, usize)> = trace
+                .iter()
+                .enumerate()
+                .filter(|(_, a)| !loops[**a] || **a == t)
+                .map(|(i, a)| (i, *a))
+                .collect();
+            for (i, (_, a)) in reduced_trace.iter().enumerate() {
+                if *a != t {
+                    continue;
+                }
+                match i.checked_sub(1).map(|j| reduced_trace[j].1) {
+                    Some(p) if p == t => {}
+                    Some(p) => {
+                        predecessors.insert(p);
+                    }
+                    None => {
+                        predecessors.insert(start);
+                    }
+                }
+                match reduced_trace.get(i + 1).map(|(_, s)| *s) {
+                    Some(s) if s == t => {}
+                    Some(s) => {
+                        successors.insert(s);
+                    }
+                    None => {
+                        successors.insert(end);
+                    }
+                }
+            }
+        }
+        let mut loop_places: Vec<PlaceID> = places
+            .iter()
+            .zip(&place_ids)
+            .filter(|((inputs, outputs), _)| predecessors.is_subset(inputs) && successors.is_subset(outputs))
+            .map(|(_, place)| *place)
+            .collect();
+        if loop_places.is_empty() {
+            // No place lies between all predecessors and successors (e.g., for `a a`):
+            // Add a place for the loop, connected to the source/sink place via silent transitions
+            let place = net.add_place(None);
+            for p in &predecessors {
+                let input = if *p == start {
+                    let silent = net.add_transition(None, None);
+                    net.add_arc(ArcType::place_to_transition(place_ids[0], silent), None);
+                    silent
+                } else {
+                    transitions[*p]
+                };
+                net.add_arc(ArcType::transition_to_place(input, place), None);
+            }
+            for s in &successors {
+                let output = if *s == end {
+                    let silent = net.add_transition(None, None);
+                    net.add_arc(ArcType::transition_to_place(silent, place_ids[1]), None);
+                    silent
+                } else {
+                    transitions[*s]
+                };
+                net.add_arc(ArcType::place_to_transition(place, output), None);
+            }
+            loop_places.push(place);
+        }
+        for place in loop_places {
+            net.add_arc(ArcType::place_to_transition(place, transitions[t]), None);
+            net.add_arc(ArcType::transition_to_place(transitions[t], place), None);
+        }
+    }
+
+    net.initial_marking = Some(Marking::from([(place_ids[0], 1)]));
+    net.final_markings = Some(vec![Marking::from([(place_ids[1], 1)])]);
+    net
+}
+
+///
+/// Discover a Petri net from an event log DataFrame using the Alpha Miner
+///
+/// Returns a json-encoding of the Petri net (with `places`, `transitions`, `arcs`, `initial_marking` and `final_markings`)
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+/// * `alpha_plus` - Optional flag to use Alpha+ (handling length-one and length-two loops)
+///
+#[pyfunction]
+#[pyo3(signature = (df, activity_col=None, case_col=None, alpha_plus=None))]
+pub fn discover_alpha_rs(
+    df: PyDataFrame,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+    alpha_plus: Option<bool>,
+) -> PyResult<String> {
+    let columns = EventColumns::new(case_col, activity_col, None);
+    let log = case_log_from_df(&df.0, &columns)
+        .map_err(|e| PyValueError::new_err(format!("Failed to read event log: {e:?}")))?;
+    let net = discover_alpha(&log, alpha_plus.unwrap_or_default());
+    Ok(petri_net_to_py(&net))
+}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use polars::prelude::*;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use super::{case_log_from_df, value_stats, CaseLog, EventColumns};
+
+/// Frequency and durations (in seconds) of a directly-follows edge
+#[derive(Debug, Clone, Default)]
+pub struct DirectlyFollowsEdge {
+    pub frequency: usize,
+    /// Durations between the two events (only recorded if both have a timestamp)
+    pub durations: Vec<f64>,
+}
+
+/// Directly-follows graph of a [CaseLog]
+#[derive(Debug, Clone, Default)]
+pub struct DirectlyFollowsGraph {
+    /// Activity names (indexed by activity id)
+    pub activities: Vec<String>,
+    /// Number of events per activity
+    pub activity_frequencies: Vec<usize>,
+    /// Number of cases starting with each activity
+    pub start_frequencies: Vec<usize>,
+    /// Number of cases ending with each activity
+    pub end_frequencies: Vec<usize>,
+    /// Edges between activity ids
+    pub edges: HashMap<(usize, usize), DirectlyFollowsEdge>,
+}
+
+impl DirectlyFollowsGraph {
+    /// Check if `b` directly follows `a` in some case
+    pub fn follows(&self, a: usize, b: usize) -> bool {
+        self.edges.contains_key(&(a, b))
+    }
+
+    /// Ids of the activities some case starts with
+    pub fn start_activities(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.activities.len()).filter(|a| self.start_frequencies[*a] > 0)
+    }
+
+    /// Ids of the activities some case ends with
+    pub fn end_activities(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.activities.len()).filter(|a| self.end_frequencies[*a] > 0)
+    }
+
+    ///
+    /// Convert the graph to a nodes [DataFrame] (`activity`, `frequency`, `start_frequency`, `end_frequency`)
+    /// and an edges [DataFrame] (`source`, `target`, `frequency` and, if `performance` is set,
+    /// `mean_duration`, `median_duration`, `min_duration`, `max_duration` in seconds)
+    ///
+    /// Both are sorted by descending frequency.
+    ///
+    pub fn to_dfs(&self, performance: bool) -> PolarsResult<(DataFrame, DataFrame)> {
+        let mut nodes: Vec<usize> = (0..self.activities.len()).collect();
+        nodes.sort_by(|a, b| {
+            self.activity_frequencies[*b]
+                .cmp(&self.activity_frequencies[*a])
+                .then_with(|| self.activities[*a].cmp(&self.activities[*b]))
+        });
+        let nodes_df = DataFrame::new(vec![
+            Column::new(
+                "activity".into(),
+                nodes.iter().map(|a| self.activities[*a].as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "frequency".into(),
+                nodes.iter().map(|a| self.activity_frequencies[*a] as u64).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "start_frequency".into(),
+                nodes.iter().map(|a| self.start_frequencies[*a] as u64).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "end_frequency".into(),
+                nodes.iter().map(|a| self.end_frequencies[*a] as u64).collect::<Vec<_>>(),
+            ),
+        ])?;
+
+        let mut edges: Vec<(&(usize, usize), &DirectlyFollowsEdge)> = self.edges.iter().collect();
+        edges.sort_by(|(ka, a), (kb, b)| {
+            b.frequency.cmp(&a.frequency).then_with(|| {
+                (&self.activities[ka.0], &self.activities[ka.1])
+                    .cmp(&(&self.activities[kb.0], &self.activities[kb.1]))
+            })
+        });
+        let mut columns = vec![
+            Column::new(
+                "source".into(),
+                edges.iter().map(|((a, _), _)| self.activities[*a].as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "target".into(),
+                edges.iter().map(|((_, b), _)| self.activities[*b].as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "frequency".into(),
+                edges.iter().map(|(_, e)| e.frequency as u64).collect::<Vec<_>>(),
+            ),
+        ];
+        if performance {
+            let stats: Vec<_> = edges
+                .iter()
+                .map(|(_, e)| value_stats(&mut e.durations.clone()))
+                .collect();
+            columns.extend([
+                Column::new(
+                    "mean_duration".into(),
+                    stats.iter().map(|s| s.map(|s| s.0)).collect::<Vec<_>>(),
+                ),
+                Column::new(
+                    "median_duration".into(),
+                    stats.iter().map(|s| s.map(|s| s.1)).collect::<Vec<_>>(),
+                ),
+                Column::new(
+                    "min_duration".into(),
+                    stats.iter().map(|s| s.map(|s| s.2)).collect::<Vec<_>>(),
+                ),
+                Column::new(
+                    "max_duration".into(),
+                    stats.iter().map(|s| s.map(|s| s.3)).collect::<Vec<_>>(),
+                ),
+            ]);
+        }
+        Ok((nodes_df, DataFrame::new(columns)?))
+    }
+}
+
+///
+/// Discover the [DirectlyFollowsGraph] of a [CaseLog]
+///
+/// Durations are only recorded if `performance` is set
+///
+pub fn discover_dfg(log: &CaseLog, performance: bool) -> DirectlyFollowsGraph {
+    let num_activities = log.activities.len();
+    let mut dfg = DirectlyFollowsGraph {
+        activities: log.activities.clone(),
+        activity_frequencies: vec![0; num_activities],
+        start_frequencies: vec![0; num_activities],
+        end_frequencies: vec![0; num_activities],
+        edges: HashMap::new(),
+    };
+    for case in &log.cases {
+        if let (Some(first), Some(last)) = (case.activities.first(), case.activities.last()) {
+            dfg.start_frequencies[*first] += 1;
+            dfg.end_frequencies[*last] += 1;
+        }
+        for a in &case.activities {
+            dfg.activity_frequencies[*a] += 1;
+        }
+        for i in 1..case.activities.len() {
+            let edge = dfg
+                .edges
+                .entry((case.activities[i - 1], case.activities[i]))
+                .or_default();
+            edge.frequency += 1;
+            if performance {
+                if let (Some(t1), Some(t2)) = (case.timestamps[i - 1], case.timestamps[i]) {
+                    edge.durations.push(t2 - t1);
+                }
+            }
+        }
+    }
+    dfg
+}
+
+///
+/// Discover the directly-follows graph of an event log DataFrame
+///
+/// Returns a dict with the DataFrames `nodes` (`activity`, `frequency`, `start_frequency`, `end_frequency`)
+/// and `edges` (`source`, `target`, `frequency` and, with `performance`, `mean_duration`, `median_duration`, `min_duration`, `max_duration` in seconds)
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+/// * `timestamp_col` - Optional timestamp column used for performance annotations (default: `time:timestamp`)
+/// * `performance` - Optional flag to add duration statistics to the edges
+///
+#[pyfunction]
+#[pyo3(signature = (df, activity_col=None, case_col=None, timestamp_col=None, performance=None))]
+pub fn discover_dfg_rs(
+    df: PyDataFrame,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+    timestamp_col: Option<String>,
+    performance: Option<bool>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let performance = performance.unwrap_or_default();
+    let columns = EventColumns::new(case_col, activity_col, timestamp_col);
+    let log = case_log_from_df(&df.0, &columns)
+        .map_err(|e| PyValueError::new_err(format!("Failed to read event log: {e:?}")))?;
+    let (nodes, edges) = discover_dfg(&log, performance)
+        .to_dfs(performance)
+        .map_err(|e| PyValueError::new_err(format!("Failed to create DataFrames: {e:?}")))?;
+    Ok(HashMap::from([
+        ("nodes".to_string(), PyDataFrame(nodes)),
+        ("edges".to_string(), PyDataFrame(edges)),
+    ]))
+}
@@ -0,0 +1,461 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::process_tree::{process_tree_to_py, ProcessTree, TreeOperator};
+
+use super::{case_log_from_df, CaseLog, EventColumns};
+
+/// Multiset of traces (activity ids) used during the recursion of the Inductive Miner
+#[derive(Debug, Clone, Default)]
+struct IMLog {
+    traces: BTreeMap<Vec<usize>, u64>,
+}
+
+impl IMLog {
+    fn add(&mut self, trace: Vec<usize>, count: u64) {
+        *self.traces.entry(trace).or_default() += count;
+    }
+
+    fn num_traces(&self) -> u64 {
+        self.traces.values().sum()
+    }
+
+    fn activities(&self) -> BTreeSet<usize> {
+        self.traces.keys().flatten().copied().collect()
+    }
+}
+
+/// Directly-follows graph (with start and end activities) of an [IMLog]
+#[derive(Debug, Clone, Default)]
+struct IMDfg {
+    activities: BTreeSet<usize>,
+    edges: BTreeMap<(usize, usize), u64>,
+    start: BTreeMap<usize, u64>,
+    end: BTreeMap<usize, u64>,
+}
+
+impl IMDfg {
+    fn new(log: &IMLog) -> Self {
+        let mut dfg = IMDfg {
+            activities: log.activities(),
+            ..Default::default()
+        };
+        for (trace, count) in &log.traces {
+            if let (Some(first), Some(last)) = (trace.first(), trace.last()) {
+                *dfg.start.entry(*first).or_default() += count;
+                *dfg.end.entry(*last).or_default() += count;
+            }
+            for pair in trace.windows(2) {
+                *dfg.edges.entry((pair[0], pair[1])).or_default() += count;
+            }
+        }
+        dfg
+    }
+
+    ///
+    /// Remove infrequent edges and start/end activities (as in IMf)
+    ///
+    /// An edge `(a, b)` is removed if its frequency is below `noise_threshold` times the frequency of the most frequent outgoing edge of `a`
+    /// (where ending the trace after `a` counts as an outgoing edge, as in ProM/pm4py).
+    /// Start/end activities are removed if their frequency is below `noise_threshold` times the frequency of the most frequent start/end activity.
+    ///
+    fn filtered(&self, noise_threshold: f64) -> Self {
+        let mut max_outgoing: HashMap<usize, u64> = HashMap::new();
+        for ((a, _), frequency) in &self.edges {
+            let max = max_outgoing.entry(*a).or_default();
+            *max = (*max).max(*frequency);
+        }
+        for (a, frequency) in &self.end {
+            let max = max_outgoing.entry(*a).or_default();
+            *max = (*max).max(*frequency);
+        }
+        let filter_max = |activities: &BTreeMap<usize, u64>| -> BTreeMap<usize, u64> {
+            let max = activities.values().copied().max().unwrap_or_default();
+            activities
+                .iter()
+                .filter(|(_, f)| **f as f64 >= noise_threshold * max as f64)
+                .map(|(a, f)| (*a, *f))
+                .collect()
+        };
+        IMDfg {
+            activities: self.activities.clone(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|((a, _), f)| **f as f64 >= noise_threshold * max_outgoing[a] as f64)
+                .map(|(e, f)| (*e, *f))
+                .collect(),
+            start: filter_max(&self.start),
+            end: filter_max(&self.end),
+        }
+    }
+
+    fn follows(&self, a: usize, b: usize) -> bool {
+        self.edges.contains_key(&(a, b))
+    }
+
+    /// Activities reachable from each activity (via at least one edge)
+    fn reachability(&self) -> HashMap<usize, BTreeSet<usize>> {
+        let mut successors: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (a, b) in self.edges.keys() {
+            successors.entry(*a).or_default().push(*b);
+        }
+        self.activities
+            .iter()
+            .map(|a| {
+                let mut reached = BTreeSet::new();
+                let mut stack: Vec<usize> = successors.get(a).cloned().unwrap_or_default();
+                while let Some(b) = stack.pop() {
+                    if reached.insert(b) {
+                        stack.extend(successors.get(&b).into_iter().flatten());
+                    }
+                }
+                (*a, reached)
+            })
+            .collect()
+    }
+}
+
+/// Groups of activities connected by the given relation (sorted by their smallest activity id)
+fn connected_components(
+    activities: &BTreeSet<usize>,
+    connected: impl Fn(usize, usize) -> bool,
+) -> Vec<BTreeSet<usize>> {
+    let mut groups: Vec<BTreeSet<usize>> = Vec::new();
+    for a in activities {
+        let (matching, mut rest): (Vec<_>, Vec<_>) = groups
+            .into_iter()
+            .partition(|g| g.iter().any(|b| connected(*a, *b) || connected(*b, *a)));
+        let mut group: BTreeSet<usize> = matching.into_iter().flatten().collect();
+        group.insert(*a);
+        rest.push(group);
+        groups = rest;
+    }
+    groups.sort();
+    groups
+}
+
+fn xor_cut(dfg: &IMDfg) -> Option<Vec<BTreeSet<usize>>> {
+    let groups = connected_components(&dfg.activities, |a, b| dfg.follows(a, b));
+    (groups.len() > 1).then_some(groups)
+}
+
+fn sequence_cut(dfg: &IMDfg) -> Option<Vec<BTreeSet<usize>>> {
+    let reach = dfg.reachability();
+    let reaches = |g1: &BTreeSet<usize>, g2: &BTreeSet<usize>| {
+        g1.iter().any(|a| g2.iter().any(|b| reach[a].contains(b)))
+    };
+    let unordered = |a: &usize, b: &usize| reach[a].contains(b) == reach[b].contains(a);
+    // Merge groups that are not ordered, i.e., that contain any pair of mutually reachable or mutually unreachable
+    // activities (or that are mutually reachable or unreachable as a whole)
+    let mut groups: Vec<BTreeSet<usize>> = dfg.activities.iter().map(|a| BTreeSet::from([*a])).collect();
+    'merge: loop {
+        for i in 0..groups.len() {
+            for j in i + 1..groups.len() {
+                if groups[i].iter().any(|a| groups[j].iter().any(|b| unordered(a, b)))
+                    || reaches(&groups[i], &groups[j]) == reaches(&groups[j], &groups[i])
+                {
+                    let group = groups.remove(j);
+                    groups[i].extend(group);
+                    continue 'merge;
+                }
+            }
+        }
+        break;
+    }
+    if groups.len() < 2 {
+        return None;
+    }
+    let predecessors: Vec<usize> = groups
+        .iter()
+        .map(|g| groups.iter().filter(|g2| reaches(g2, g)).count())
+        .collect();
+    let mut order: Vec<usize> = (0..groups.len()).collect();
+    order.sort_by_key(|i| predecessors[*i]);
+    Some(order.into_iter().map(|i| groups[i].clone()).collect())
+}
+
+fn parallel_cut(dfg: &IMDfg) -> Option<Vec<BTreeSet<usize>>> {
+    let groups = connected_components(&dfg.activities, |a, b| {
+        !(dfg.follows(a, b) && dfg.follows(b, a))
+    });
+    // Every concurrent group needs a start and an end activity; other groups are merged into the first valid one
+    let (mut valid, invalid): (Vec<_>, Vec<_>) = groups.into_iter().partition(|g| {
+        g.iter().any(|a| dfg.start.contains_key(a)) && g.iter().any(|a| dfg.end.contains_key(a))
+    });
+    if valid.len() < 2 {
+        return None;
+    }
+    valid[0].extend(invalid.into_iter().flatten());
+    valid.sort();
+    Some(valid)
+}
+
+fn loop_cut(dfg: &IMDfg) -> Option<Vec<BTreeSet<usize>>> {
+    let mut do_group: BTreeSet<usize> = dfg.start.keys().chain(dfg.end.keys()).copied().collect();
+    if do_group.is_empty() {
+        return None;
+    }
+    let others: BTreeSet<usize> = dfg.activities.difference(&do_group).copied().collect();
+    let components = connected_components(&others, |a, b| dfg.follows(a, b));
+    let only_start = |a: &usize| dfg.start.contains_key(a) && !dfg.end.contains_key(a);
+    let only_end = |a: &usize| dfg.end.contains_key(a) && !dfg.start.contains_key(a);
+    let mut redo_groups = Vec::new();
+    for component in components {
+        let valid = dfg.edges.keys().all(|(a, b)| {
+            // Redo parts are only entered after end activities and only left to start activities
+            !(only_start(a) && component.contains(b)) && !(component.contains(a) && only_end(b))
+        }) && component.iter().all(|c| {
+            let entered = dfg.end.keys().any(|e| dfg.follows(*e, *c));
+            let left = dfg.start.keys().any(|s| dfg.follows(*c, *s));
+            (!entered || dfg.end.keys().all(|e| dfg.follows(*e, *c)))
+                && (!left || dfg.start.keys().all(|s| dfg.follows(*c, *s)))
+        });
+        if valid {
+            redo_groups.push(component);
+        } else {
+            do_group.extend(component);
+        }
+    }
+    if redo_groups.is_empty() {
+        return None;
+    }
+    let mut groups = vec![do_group];
+    groups.extend(redo_groups);
+    Some(groups)
+}
+
+fn find_cut(dfg: &IMDfg) -> Option<(TreeOperator, Vec<BTreeSet<usize>>)> {
+    xor_cut(dfg)
+        .map(|g| (TreeOperator::Xor, g))
+        .or_else(|| sequence_cut(dfg).map(|g| (TreeOperator::Sequence, g)))
+        .or_else(|| parallel_cut(dfg).map(|g| (TreeOperator::Parallel, g)))
+        .or_else(|| loop_cut(dfg).map(|g| (TreeOperator::Loop, g)))
+}
+
+/// Split a log according to a cut (projecting traces on the groups, which also handles traces not fitting a filtered cut)
+fn split_log(log: &IMLog, operator: TreeOperator, groups: &[BTreeSet<usize>]) -> Vec<IMLog> {
+    let mut sublogs = vec![IMLog::default(); groups.len()];
+    let group_of = |a: &usize| groups.iter().position(|g| g.contains(a)).unwrap_or_default();
+    for (trace, count) in &log.traces {
+        match operator {
+            TreeOperator::Xor => {
+                // Traces are assigned to the group containing most of their events
+                let mut group_counts = vec![0; groups.len()];
+                for a in trace {
+                    group_counts[group_of(a)] += 1;
+                }
+                let group = (0..groups.len())
+                    .max_by_key(|g| (group_counts[*g], std::cmp::Reverse(*g)))
+                    .unwrap_or_default();
+                let projected = trace.iter().copied().filter(|a| groups[group].contains(a)).collect();
+                sublogs[group].add(projected, *count);
+            }
+            TreeOperator::Sequence | TreeOperator::Parallel => {
+                for (group, sublog) in groups.iter().zip(sublogs.iter_mut()) {
+                    let projected = trace.iter().copied().filter(|a| group.contains(a)).collect();
+                    sublog.add(projected, *count);
+                }
+            }
+            TreeOperator::Loop => {
+                // Traces are split into maximal runs of the same group, with empty do parts inserted where a do part is missing
+                let mut expecting_do = true;
+                let mut i = 0;
+                while i < trace.len() {
+                    let group = group_of(&trace[i]);
+                    let run_end = (i..trace.len())
+                        .find(|j| group_of(&trace[*j]) != group)
+                        .unwrap_or(trace.len());
+                    if group == 0 {
+                        expecting_do = false;
+                    } else {
+                        if expecting_do {
+                            sublogs[0].add(Vec::new(), *count);
+                        }
+                        expecting_do = true;
+                    }
+                    sublogs[group].add(trace[i..run_end].to_vec(), *count);
+                    i = run_end;
+                }
+                if expecting_do {
+                    sublogs[0].add(Vec::new(), *count);
+                }
+            }
+        }
+    }
+    sublogs
+}
+
+/// Inductive Miner recursion (IMf if `noise_threshold` is positive)
+struct InductiveMiner<'a> {
+    activities: &'a [String],
+    noise_threshold: f64,
+}
+
+impl InductiveMiner<'_> {
+    fn activity(&self, a: usize) -> ProcessTree {
+        ProcessTree::Activity(self.activities[a].clone())
+    }
+
+    fn mine(&self, log: &IMLog) -> ProcessTree {
+        let num_traces = log.num_traces();
+        if num_traces == 0 || log.traces.keys().all(Vec::is_empty) {
+            return ProcessTree::Tau;
+        }
+        if let Some(num_empty) = log.traces.get(&Vec::new()) {
+            let mut non_empty = log.clone();
+            non_empty.traces.remove(&Vec::new());
+            return if *num_empty as f64 / num_traces as f64 > self.noise_threshold {
+                ProcessTree::Operator(TreeOperator::Xor, vec![ProcessTree::Tau, self.mine(&non_empty)])
+            } else {
+                self.mine(&non_empty)
+            };
+        }
+        let activities = log.activities();
+        if activities.len() == 1 {
+            let a = *activities.first().unwrap();
+            return if log.traces.keys().all(|t| t.len() == 1) {
+                self.activity(a)
+            } else {
+                ProcessTree::Operator(TreeOperator::Loop, vec![self.activity(a), ProcessTree::Tau])
+            };
+        }
+        let dfg = IMDfg::new(log);
+        let cut = find_cut(&dfg).or_else(|| {
+            (self.noise_threshold > 0.0)
+                .then(|| find_cut(&dfg.filtered(self.noise_threshold)))
+                .flatten()
+        });
+        if let Some((operator, groups)) = cut {
+            let children = split_log(log, operator, &groups)
+                .iter()
+                .map(|sublog| self.mine(sublog))
+                .collect();
+            return ProcessTree::Operator(operator, children);
+        }
+        self.fall_through(log, &dfg, &activities)
+    }
+
+    /// Project a log on all activities except `a`
+    fn without_activity(log: &IMLog, a: usize) -> IMLog {
+        let mut rest = IMLog::default();
+        for (trace, count) in &log.traces {
+            rest.add(trace.iter().copied().filter(|b| *b != a).collect(), *count);
+        }
+        rest
+    }
+
+    /// Split traces before every activity in `split_before` that directly follows an activity in `split_after` (if given)
+    fn split_traces(
+        log: &IMLog,
+        split_after: Option<&BTreeMap<usize, u64>>,
+        split_before: &BTreeMap<usize, u64>,
+    ) -> IMLog {
+        let mut split = IMLog::default();
+        for (trace, count) in &log.traces {
+            let mut start = 0;
+            for i in 1..trace.len() {
+                if split_after.is_none_or(|after| after.contains_key(&trace[i - 1]))
+                    && split_before.contains_key(&trace[i])
+                {
+                    split.add(trace[start..i].to_vec(), *count);
+                    start = i;
+                }
+            }
+            split.add(trace[start..].to_vec(), *count);
+        }
+        split
+    }
+
+    ///
+    /// Fall-throughs (in the order of ProM/pm4py, empty traces are already handled in [InductiveMiner::mine]):
+    /// activity once per trace, activity concurrent, strict tau loop, tau loop and flower model
+    ///
+    fn fall_through(&self, log: &IMLog, dfg: &IMDfg, activities: &BTreeSet<usize>) -> ProcessTree {
+        // Activity once per trace: concurrent to the rest of the log
+        if let Some(a) = activities
+            .iter()
+            .find(|a| log.traces.keys().all(|t| t.iter().filter(|b| b == a).count() == 1))
+        {
+            return ProcessTree::Operator(
+                TreeOperator::Parallel,
+                vec![self.activity(*a), self.mine(&Self::without_activity(log, *a))],
+            );
+        }
+        // Activity concurrent: an activity whose removal allows a cut of the rest of the log
+        for a in activities {
+            let rest = Self::without_activity(log, *a);
+            if find_cut(&IMDfg::new(&rest)).is_some() {
+                return ProcessTree::Operator(
+                    TreeOperator::Parallel,
+                    vec![self.activity(*a), self.mine(&rest)],
+                );
+            }
+        }
+        // Strict tau loop: split traces where an end activity is followed by a start activity
+        // Tau loop: split traces before every start activity
+        for split in [
+            Self::split_traces(log, Some(&dfg.end), &dfg.start),
+            Self::split_traces(log, None, &dfg.start),
+        ] {
+            if split.num_traces() > log.num_traces() {
+                return ProcessTree::Operator(TreeOperator::Loop, vec![self.mine(&split), ProcessTree::Tau]);
+            }
+        }
+        // Flower model
+        let mut children = vec![ProcessTree::Tau];
+        children.extend(activities.iter().map(|a| self.activity(*a)));
+        ProcessTree::Operator(TreeOperator::Loop, children)
+    }
+}
+
+///
+/// Discover a [ProcessTree] using the Inductive Miner
+///
+/// With a positive `noise_threshold`, the infrequent variant IMf is used: if no cut is found on the directly-follows graph,
+/// infrequent edges and start/end activities are filtered before trying again, and empty traces are ignored if their fraction
+/// does not exceed the threshold.
+///
+pub fn discover_inductive(log: &CaseLog, noise_threshold: f64) -> ProcessTree {
+    let mut im_log = IMLog::default();
+    for case in &log.cases {
+        im_log.add(case.activities.clone(), 1);
+    }
+    InductiveMiner {
+        activities: &log.activities,
+        noise_threshold,
+    }
+    .mine(&im_log)
+}
+
+///
+/// Discover a process tree from an event log DataFrame using the Inductive Miner (IM, or IMf with a positive noise threshold)
+///
+/// Returns a json-encoding of the process tree (leaves as `{"label": ...}` with `null` for tau, inner nodes as `{"operator": ..., "children": [...]}`)
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+/// * `noise_threshold` - Optional noise threshold between 0 and 1 for IMf (default: 0, i.e., IM)
+///
+#[pyfunction]
+#[pyo3(signature = (df, activity_col=None, case_col=None, noise_threshold=None))]
+pub fn discover_inductive_rs(
+    df: PyDataFrame,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+    noise_threshold: Option<f64>,
+) -> PyResult<String> {
+    let noise_threshold = noise_threshold.unwrap_or_default();
+    if !(0.0..=1.0).contains(&noise_threshold) {
+        return Err(PyValueError::new_err(format!(
+            "Invalid noise threshold {noise_threshold} (expected a value between 0 and 1)"
+        )));
+    }
+    let columns = EventColumns::new(case_col, activity_col, None);
+    let log = case_log_from_df(&df.0, &columns)
+        .map_err(|e| PyValueError::new_err(format!("Failed to read event log: {e:?}")))?;
+    Ok(process_tree_to_py(&discover_inductive(&log, noise_threshold)))
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use polars::prelude::*;
+
+pub mod alignments;
+pub mod alpha;
+pub mod dfg;
+pub mod inductive;
+pub mod summary;
+pub mod token_replay;
+pub mod variants;
+
+pub const CASE_ID_KEY: &str = "case:concept:name";
+pub const ACTIVITY_KEY: &str = "concept:name";
+pub const TIMESTAMP_KEY: &str = "time:timestamp";
+
+/// Events of a single case (in the order of the rows of the event [DataFrame])
+#[derive(Debug, Clone)]
+pub struct CaseEvents {
+    pub case_id: String,
+    /// Row indices of the events in the event [DataFrame]
+    pub rows: Vec<usize>,
+    /// Activity ids of the events (see [CaseLog::activities])
+    pub activities: Vec<usize>,
+    /// Timestamps of the events (in seconds since the Unix epoch)
+    pub timestamps: Vec<Option<f64>>,
+}
+
+impl CaseEvents {
+    /// Time between the first and the last event of the case (in seconds)
+    pub fn duration(&self) -> Option<f64> {
+        let timestamps = self.timestamps.iter().flatten();
+        let min = timestamps.clone().copied().reduce(f64::min)?;
+        let max = timestamps.copied().reduce(f64::max)?;
+        Some(max - min)
+    }
+}
+
+/// Case-centric view of an event [DataFrame] (as returned by `import_xes_rs`), with activities mapped to ids
+#[derive(Debug, Clone, Default)]
+pub struct CaseLog {
+    /// Activity names (indexed by activity id)
+    pub activities: Vec<String>,
+    /// Cases in the order of their first occurrence
+    pub cases: Vec<CaseEvents>,
+}
+
+/// Column names of an event [DataFrame] used for analysis
+#[derive(Debug, Clone)]
+pub struct EventColumns {
+    pub case_col: String,
+    pub activity_col: String,
+    /// Timestamp column (ignored if it does not exist in the [DataFrame] or is no datetime column)
+    pub timestamp_col: String,
+}
+
+impl Default for EventColumns {
+    fn default() -> Self {
+        Self {
+            case_col: CASE_ID_KEY.to_string(),
+            activity_col: ACTIVITY_KEY.to_string(),
+            timestamp_col: TIMESTAMP_KEY.to_string(),
+        }
+    }
+}
+
+impl EventColumns {
+    /// Columns with the given names (or the standard XES columns if `None`)
+    pub fn new(
+        case_col: Option<String>,
+        activity_col: Option<String>,
+        timestamp_col: Option<String>,
+    ) -> Self {
+        let default = Self::default();
+        Self {
+            case_col: case_col.unwrap_or(default.case_col),
+            activity_col: activity_col.unwrap_or(default.activity_col),
+            timestamp_col: timestamp_col.unwrap_or(default.timestamp_col),
+        }
+    }
+}
+
+/// Values of a column cast to strings (null values become empty strings)
+pub fn column_strings(df: &DataFrame, name: &str) -> PolarsResult<Vec<String>> {
+    Ok(df
+        .column(name)?
+        .cast(&DataType::String)?
+        .str()?
+        .into_iter()
+        .map(|s| s.unwrap_or_default().to_string())
+        .collect())
+}
+
+/// Values of an identifier column cast to strings (fails for null values, naming the column and row)
+pub fn column_ids(df: &DataFrame, name: &str) -> PolarsResult<Vec<String>> {
+    df.column(name)?
+        .cast(&DataType::String)?
+        .str()?
+        .into_iter()
+        .enumerate()
+        .map(|(row, s)| match s {
+            Some(s) => Ok(s.to_string()),
+            None => polars_bail!(ComputeError: "Column '{}' has a null value in row {}", name, row),
+        })
+        .collect()
+}
+
+/// Values of a datetime column in seconds since the Unix epoch
+pub fn column_seconds(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<f64>>> {
+    let col = df.column(name)?;
+    let unit = match col.dtype() {
+        DataType::Datetime(unit, _) => *unit,
+        dtype => polars_bail!(InvalidOperation: "Column '{}' has type {} (expected datetime)", name, dtype),
+    };
+    let per_second = match unit {
+        TimeUnit::Nanoseconds => 1e9,
+        TimeUnit::Microseconds => 1e6,
+        TimeUnit::Milliseconds => 1e3,
+    };
+    Ok(col
+        .cast(&DataType::Int64)?
+        .i64()?
+        .into_iter()
+        .map(|t| t.map(|t| t as f64 / per_second))
+        .collect())
+}
+
+///
+/// Group the events of a [DataFrame] into cases
+///
+/// Events keep the order of the rows of the [DataFrame] within each case (as in the XES file for DataFrames returned by `import_xes_rs`).
+///
+pub fn case_log_from_df(df: &DataFrame, columns: &EventColumns) -> PolarsResult<CaseLog> {
+    let case_ids = column_strings(df, &columns.case_col)?;
+    let activity_names = column_strings(df, &columns.activity_col)?;
+    let timestamps = match df.column(&columns.timestamp_col) {
+        Ok(c) if matches!(c.dtype(), DataType::Datetime(_, _)) => {
+            column_seconds(df, &columns.timestamp_col)?
+        }
+        _ => vec![None; df.height()],
+    };
+    let mut log = CaseLog::default();
+    let mut activity_ids: HashMap<String, usize> = HashMap::new();
+    let mut case_indices: HashMap<String, usize> = HashMap::new();
+    for (row, ((case_id, activity), timestamp)) in case_ids
+        .into_iter()
+        .zip(activity_names)
+        .zip(timestamps)
+        .enumerate()
+    {
+        let activity = *activity_ids.entry(activity).or_insert_with_key(|a| {
+            log.activities.push(a.clone());
+            log.activities.len() - 1
+        });
+        let case_index = *case_indices.entry(case_id).or_insert_with_key(|case_id| {
+            log.cases.push(CaseEvents {
+                case_id: case_id.clone(),
+                rows: Vec::new(),
+                activities: Vec::new(),
+                timestamps: Vec::new(),
+            });
+            log.cases.len() - 1
+        });
+        let case = &mut log.cases[case_index];
+        case.rows.push(row);
+        case.activities.push(activity);
+        case.timestamps.push(timestamp);
+    }
+    Ok(log)
+}
+
+/// Mean, median, minimum and maximum of a list of values (sorts the values)
+pub fn value_stats(values: &mut [f64]) -> Option<(f64, f64, f64, f64)> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let n = values.len();
+    let mean = values.iter().sum::<f64>() / n as f64;
+    let median = if n % 2 == 0 {
+        (values[n / 2 - 1] + values[n / 2]) / 2.0
+    } else {
+        values[n / 2]
+    };
+    Some((mean, median, values[0], values[n - 1]))
+}
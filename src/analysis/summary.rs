@@ -0,0 +1,246 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+use chrono::{DateTime, Datelike, NaiveDate};
+use polars::prelude::*;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::parse_option;
+
+use super::{case_log_from_df, column_seconds, value_stats, EventColumns};
+
+pub const RESOURCE_KEY: &str = "org:resource";
+
+/// Size of the time buckets events are counted in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeBucket {
+    Hour,
+    #[default]
+    Day,
+    /// Weeks starting on Monday
+    Week,
+    Month,
+    Year,
+}
+
+impl FromStr for TimeBucket {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hour" => Ok(Self::Hour),
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            "month" => Ok(Self::Month),
+            "year" => Ok(Self::Year),
+            _ => Err(format!(
+                "Invalid time bucket '{s}' (expected 'hour', 'day', 'week', 'month' or 'year')"
+            )),
+        }
+    }
+}
+
+impl TimeBucket {
+    /// Start of the (UTC) bucket containing the given timestamp (both in milliseconds since the Unix epoch)
+    pub fn bucket_start(&self, millis: i64) -> Option<i64> {
+        const HOUR: i64 = 3_600_000;
+        const DAY: i64 = 24 * HOUR;
+        let date = DateTime::from_timestamp_millis(millis)?.date_naive();
+        let start = match self {
+            TimeBucket::Hour => return Some(millis.div_euclid(HOUR) * HOUR),
+            TimeBucket::Day => return Some(millis.div_euclid(DAY) * DAY),
+            TimeBucket::Week => {
+                date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            TimeBucket::Month => NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?,
+            TimeBucket::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1)?,
+        };
+        Some(start.and_hms_opt(0, 0, 0)?.and_utc().timestamp_millis())
+    }
+}
+
+/// Histogram of (non-negative integer) values as [DataFrame] with the columns `value_name` and `count_name`, sorted by value
+pub fn histogram_df(
+    values: impl IntoIterator<Item = usize>,
+    value_name: &str,
+    count_name: &str,
+) -> PolarsResult<DataFrame> {
+    let mut counts: BTreeMap<usize, u64> = BTreeMap::new();
+    for value in values {
+        *counts.entry(value).or_default() += 1;
+    }
+    DataFrame::new(vec![
+        Column::new(
+            value_name.into(),
+            counts.keys().map(|v| *v as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(count_name.into(), counts.values().copied().collect::<Vec<_>>()),
+    ])
+}
+
+/// Single-row [DataFrame] with the `count`, `mean`, `median`, `min` and `max` of the given values
+pub fn distribution_df(mut values: Vec<f64>) -> PolarsResult<DataFrame> {
+    let stats = value_stats(&mut values);
+    DataFrame::new(vec![
+        Column::new("count".into(), [values.len() as u64]),
+        Column::new("mean".into(), [stats.map(|s| s.0)]),
+        Column::new("median".into(), [stats.map(|s| s.1)]),
+        Column::new("min".into(), [stats.map(|s| s.2)]),
+        Column::new("max".into(), [stats.map(|s| s.3)]),
+    ])
+}
+
+///
+/// Number of distinct values and ratio of null values of every column of a [DataFrame]
+///
+/// Returns a [DataFrame] with the columns `column`, `dtype`, `cardinality` (null if distinct values cannot be determined for the type)
+/// and `null_ratio`
+///
+pub fn attribute_summary_df(df: &DataFrame) -> PolarsResult<DataFrame> {
+    let height = df.height().max(1) as f64;
+    let columns = df.get_columns();
+    DataFrame::new(vec![
+        Column::new(
+            "column".into(),
+            columns.iter().map(|c| c.name().as_str()).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "dtype".into(),
+            columns.iter().map(|c| c.dtype().to_string()).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "cardinality".into(),
+            columns
+                .iter()
+                .map(|c| c.as_materialized_series().n_unique().ok().map(|n| n as u64))
+                .collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "null_ratio".into(),
+            columns
+                .iter()
+                .map(|c| c.null_count() as f64 / height)
+                .collect::<Vec<_>>(),
+        ),
+    ])
+}
+
+///
+/// Number of events per time bucket of a datetime column
+///
+/// Returns a [DataFrame] with the columns `bucket` (start of the bucket, as UTC datetime) and `events`, sorted by bucket.
+/// Events without timestamp are not counted.
+///
+pub fn events_per_bucket_df(
+    df: &DataFrame,
+    timestamp_col: &str,
+    bucket: TimeBucket,
+) -> PolarsResult<DataFrame> {
+    let mut counts: BTreeMap<i64, u64> = BTreeMap::new();
+    for seconds in column_seconds(df, timestamp_col)?.into_iter().flatten() {
+        if let Some(start) = bucket.bucket_start((seconds * 1000.0).floor() as i64) {
+            *counts.entry(start).or_default() += 1;
+        }
+    }
+    DataFrame::new(vec![
+        Column::new("bucket".into(), counts.keys().copied().collect::<Vec<_>>())
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC)))?,
+        Column::new("events".into(), counts.values().copied().collect::<Vec<_>>()),
+    ])
+}
+
+///
+/// Summarize an event [DataFrame]
+///
+/// Returns the [DataFrame]s
+/// * `overview`: single row with the number of `cases`, `events`, `activities` and `resources` (null without resource column),
+/// * `trace_lengths`: `length` and number of `cases`,
+/// * `case_durations`: distribution of case durations in seconds (see [distribution_df]),
+/// * `events_per_bucket`: `bucket` and number of `events` (only if the timestamp column is a datetime column), and
+/// * `attributes`: cardinalities and null ratios of all columns (see [attribute_summary_df])
+///
+pub fn log_summary(
+    df: &DataFrame,
+    columns: &EventColumns,
+    resource_col: &str,
+    bucket: TimeBucket,
+) -> PolarsResult<Vec<(&'static str, DataFrame)>> {
+    let log = case_log_from_df(df, columns)?;
+    let resources = match df.column(resource_col) {
+        Ok(c) => Some(c.drop_nulls().as_materialized_series().n_unique()? as u64),
+        Err(_) => None,
+    };
+    let overview = DataFrame::new(vec![
+        Column::new("cases".into(), [log.cases.len() as u64]),
+        Column::new("events".into(), [df.height() as u64]),
+        Column::new("activities".into(), [log.activities.len() as u64]),
+        Column::new("resources".into(), [resources]),
+    ])?;
+    let mut summary = vec![
+        ("overview", overview),
+        (
+            "trace_lengths",
+            histogram_df(log.cases.iter().map(|c| c.rows.len()), "length", "cases")?,
+        ),
+        (
+            "case_durations",
+            distribution_df(log.cases.iter().filter_map(|c| c.duration()).collect())?,
+        ),
+    ];
+    if matches!(
+        df.column(&columns.timestamp_col).map(|c| c.dtype()),
+        Ok(DataType::Datetime(_, _))
+    ) {
+        summary.push((
+            "events_per_bucket",
+            events_per_bucket_df(df, &columns.timestamp_col, bucket)?,
+        ));
+    }
+    summary.push(("attributes", attribute_summary_df(df)?));
+    Ok(summary)
+}
+
+///
+/// Summarize an event log DataFrame
+///
+/// Returns a dict with the DataFrames
+/// * `overview` (`cases`, `events`, `activities`, `resources`),
+/// * `trace_lengths` (`length`, `cases`),
+/// * `case_durations` (`count`, `mean`, `median`, `min`, `max` in seconds),
+/// * `events_per_bucket` (`bucket`, `events`; only if the timestamp column is a datetime column), and
+/// * `attributes` (`column`, `dtype`, `cardinality`, `null_ratio`)
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+/// * `timestamp_col` - Optional timestamp column (default: `time:timestamp`)
+/// * `resource_col` - Optional resource column (default: `org:resource`)
+/// * `time_bucket` - Optional size of the time buckets events are counted in: `"hour"`, `"day"` (default), `"week"`, `"month"` or `"year"`
+///
+#[pyfunction]
+#[pyo3(signature = (df, activity_col=None, case_col=None, timestamp_col=None, resource_col=None, time_bucket=None))]
+pub fn log_summary_rs(
+    df: PyDataFrame,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+    timestamp_col: Option<String>,
+    resource_col: Option<String>,
+    time_bucket: Option<String>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let bucket: TimeBucket = parse_option(time_bucket)?;
+    let columns = EventColumns::new(case_col, activity_col, timestamp_col);
+    let summary = log_summary(
+        &df.0,
+        &columns,
+        resource_col.as_deref().unwrap_or(RESOURCE_KEY),
+        bucket,
+    )
+    .map_err(|e| PyValueError::new_err(format!("Failed to summarize event log: {e:?}")))?;
+    Ok(summary
+        .into_iter()
+        .map(|(name, df)| (name.to_string(), PyDataFrame(df)))
+        .collect())
+}
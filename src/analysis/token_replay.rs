@@ -0,0 +1,261 @@
+use std::collections::{HashSet, VecDeque};
+
+use polars::prelude::*;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult, Python};
+use pyo3_polars::PyDataFrame;
+use rayon::prelude::*;
+
+use crate::petri_net::{petri_net_from_py, IndexedPetriNet};
+
+use super::{case_log_from_df, variants::variants, CaseLog, EventColumns};
+
+/// Maximal number of markings explored when searching for silent transitions to fire
+const MAX_SILENT_STATES: usize = 10_000;
+
+/// Token counts of replaying a trace on a Petri net
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenReplayResult {
+    pub missing: u64,
+    pub remaining: u64,
+    pub produced: u64,
+    pub consumed: u64,
+    /// Number of events whose activity has no transition in the net (these events are skipped)
+    pub unknown_activities: u64,
+}
+
+impl TokenReplayResult {
+    /// Trace fitness `0.5 * (1 - missing / consumed) + 0.5 * (1 - remaining / produced)`
+    pub fn fitness(&self) -> f64 {
+        token_fitness(self.missing, self.consumed, self.remaining, self.produced)
+    }
+
+    /// A trace fits if no tokens are missing or remaining and all activities have a transition
+    pub fn is_fit(&self) -> bool {
+        self.missing == 0 && self.remaining == 0 && self.unknown_activities == 0
+    }
+}
+
+fn token_fitness(missing: u64, consumed: u64, remaining: u64, produced: u64) -> f64 {
+    let ratio = |a: u64, b: u64| if b == 0 { 0.0 } else { a as f64 / b as f64 };
+    0.5 * (1.0 - ratio(missing, consumed)) + 0.5 * (1.0 - ratio(remaining, produced))
+}
+
+/// Shortest sequence of silent transitions leading from `marking` to a marking satisfying `target` (breadth-first search)
+fn silent_path(
+    net: &IndexedPetriNet,
+    marking: &[u64],
+    target: impl Fn(&[u64]) -> bool,
+) -> Option<Vec<usize>> {
+    let silent: Vec<usize> = (0..net.transitions.len())
+        .filter(|t| net.transitions[*t].label.is_none())
+        .collect();
+    let mut visited: HashSet<Vec<u64>> = HashSet::from([marking.to_vec()]);
+    let mut queue: VecDeque<(Vec<u64>, Vec<usize>)> = VecDeque::from([(marking.to_vec(), Vec::new())]);
+    while let Some((marking, path)) = queue.pop_front() {
+        for t in silent.iter().filter(|t| net.is_enabled(**t, &marking)) {
+            let mut next = marking.clone();
+            net.fire(*t, &mut next);
+            let mut next_path = path.clone();
+            next_path.push(*t);
+            if target(&next) {
+                return Some(next_path);
+            }
+            if visited.len() < MAX_SILENT_STATES && visited.insert(next.clone()) {
+                queue.push_back((next, next_path));
+            }
+        }
+    }
+    None
+}
+
+/// Replays traces (as activity ids of a [CaseLog]) on an [IndexedPetriNet]
+pub struct TokenReplayer<'a> {
+    net: &'a IndexedPetriNet,
+    /// Transitions labeled with each activity (by activity id)
+    activity_transitions: Vec<Vec<usize>>,
+}
+
+impl<'a> TokenReplayer<'a> {
+    pub fn new(net: &'a IndexedPetriNet, activities: &[String]) -> Self {
+        let activity_transitions = activities
+            .iter()
+            .map(|a| {
+                (0..net.transitions.len())
+                    .filter(|t| net.transitions[*t].label.as_ref() == Some(a))
+                    .collect()
+            })
+            .collect();
+        Self {
+            net,
+            activity_transitions,
+        }
+    }
+
+    fn fire(&self, transition: usize, marking: &mut [u64], result: &mut TokenReplayResult) {
+        let t = &self.net.transitions[transition];
+        result.consumed += t.inputs.iter().map(|(_, w)| w).sum::<u64>();
+        result.produced += t.outputs.iter().map(|(_, w)| w).sum::<u64>();
+        self.net.fire(transition, marking);
+    }
+
+    ///
+    /// Replay a trace
+    ///
+    /// For every event, an enabled transition with the activity as label is fired. If none is enabled, silent transitions
+    /// are fired to enable one if possible; otherwise, the missing tokens of the transition with the fewest missing tokens are added.
+    /// At the end, silent transitions are fired to reach the final marking if possible, before the final marking is consumed.
+    ///
+    pub fn replay(&self, trace: &[usize]) -> TokenReplayResult {
+        let net = self.net;
+        let mut marking = net.initial_marking.clone();
+        let mut result = TokenReplayResult {
+            produced: marking.iter().sum(),
+            ..Default::default()
+        };
+        for activity in trace {
+            let candidates = &self.activity_transitions[*activity];
+            if candidates.is_empty() {
+                result.unknown_activities += 1;
+                continue;
+            }
+            let enabled = |m: &[u64]| candidates.iter().copied().find(|t| net.is_enabled(*t, m));
+            let transition = match enabled(&marking) {
+                Some(t) => t,
+                None => match silent_path(net, &marking, |m| enabled(m).is_some()) {
+                    Some(path) => {
+                        for t in path {
+                            self.fire(t, &mut marking, &mut result);
+                        }
+                        enabled(&marking).unwrap_or(candidates[0])
+                    }
+                    None => {
+                        let t = *candidates
+                            .iter()
+                            .min_by_key(|t| net.missing_tokens(**t, &marking))
+                            .unwrap();
+                        for (p, w) in &net.transitions[t].inputs {
+                            if marking[*p] < *w {
+                                result.missing += w - marking[*p];
+                                marking[*p] = *w;
+                            }
+                        }
+                        t
+                    }
+                },
+            };
+            self.fire(transition, &mut marking, &mut result);
+        }
+        if marking != net.final_marking {
+            if let Some(path) = silent_path(net, &marking, |m| m == net.final_marking.as_slice()) {
+                for t in path {
+                    self.fire(t, &mut marking, &mut result);
+                }
+            }
+        }
+        for (tokens, expected) in marking.iter().zip(&net.final_marking) {
+            result.missing += expected.saturating_sub(*tokens);
+            result.remaining += tokens.saturating_sub(*expected);
+        }
+        result.consumed += net.final_marking.iter().sum::<u64>();
+        result
+    }
+}
+
+///
+/// Replay all cases of a [CaseLog] on an [IndexedPetriNet] (once per variant, in parallel)
+///
+/// Returns the results in the order of the cases of the log
+///
+pub fn token_replay(log: &CaseLog, net: &IndexedPetriNet) -> Vec<TokenReplayResult> {
+    let replayer = TokenReplayer::new(net, &log.activities);
+    let variants = variants(log);
+    let variant_results: Vec<TokenReplayResult> = variants
+        .par_iter()
+        .map(|v| replayer.replay(&v.activities))
+        .collect();
+    let mut results = vec![TokenReplayResult::default(); log.cases.len()];
+    for (variant, result) in variants.iter().zip(variant_results) {
+        for case in &variant.cases {
+            results[*case] = result;
+        }
+    }
+    results
+}
+
+///
+/// Log fitness `0.5 * (1 - missing / consumed) + 0.5 * (1 - remaining / produced)` over the token counts of all cases
+///
+pub fn log_fitness(results: &[TokenReplayResult]) -> f64 {
+    let sum = |f: fn(&TokenReplayResult) -> u64| results.iter().map(f).sum::<u64>();
+    token_fitness(
+        sum(|r| r.missing),
+        sum(|r| r.consumed),
+        sum(|r| r.remaining),
+        sum(|r| r.produced),
+    )
+}
+
+///
+/// Convert token replay results to a [DataFrame] with one row per case (`case_col`, `fitness`, `is_fit`, `missing`, `remaining`,
+/// `produced`, `consumed` and `unknown_activities`)
+///
+pub fn token_replay_to_df(
+    log: &CaseLog,
+    results: &[TokenReplayResult],
+    case_col: &str,
+) -> PolarsResult<DataFrame> {
+    let column = |name: &str, f: fn(&TokenReplayResult) -> u64| {
+        Column::new(name.into(), results.iter().map(f).collect::<Vec<_>>())
+    };
+    DataFrame::new(vec![
+        Column::new(
+            case_col.into(),
+            log.cases.iter().map(|c| c.case_id.as_str()).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "fitness".into(),
+            results.iter().map(TokenReplayResult::fitness).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "is_fit".into(),
+            results.iter().map(TokenReplayResult::is_fit).collect::<Vec<_>>(),
+        ),
+        column("missing", |r| r.missing),
+        column("remaining", |r| r.remaining),
+        column("produced", |r| r.produced),
+        column("consumed", |r| r.consumed),
+        column("unknown_activities", |r| r.unknown_activities),
+    ])
+}
+
+///
+/// Token-based replay of an event log DataFrame on a Petri net
+///
+/// Returns a tuple of a DataFrame with one row per case (case id, `fitness`, `is_fit`, `missing`, `remaining`, `produced`,
+/// `consumed` and `unknown_activities`) and the log fitness
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `net` - The json-encoding of the Petri net (e.g., as returned by `discover_alpha_rs` or `import_pnml_rs`)
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+///
+#[pyfunction]
+#[pyo3(signature = (df, net, activity_col=None, case_col=None))]
+pub fn token_replay_rs(
+    py: Python<'_>,
+    df: PyDataFrame,
+    net: String,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+) -> PyResult<(PyDataFrame, f64)> {
+    let net = IndexedPetriNet::new(&petri_net_from_py(&net)?).map_err(PyValueError::new_err)?;
+    let columns = EventColumns::new(case_col, activity_col, None);
+    py.allow_threads(|| {
+        let log = case_log_from_df(&df.0, &columns)
+            .map_err(|e| PyValueError::new_err(format!("Failed to read event log: {e:?}")))?;
+        let results = token_replay(&log, &net);
+        let results_df = token_replay_to_df(&log, &results, &columns.case_col)
+            .map_err(|e| PyValueError::new_err(format!("Failed to create DataFrame: {e:?}")))?;
+        Ok((PyDataFrame(results_df), log_fitness(&results)))
+    })
+}
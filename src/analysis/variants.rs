@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use polars::prelude::*;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use super::{case_log_from_df, CaseLog, EventColumns};
+
+/// A trace variant (distinct activity sequence) and the cases following it
+#[derive(Debug, Clone)]
+pub struct Variant {
+    /// Activity ids of the variant (see [CaseLog::activities])
+    pub activities: Vec<usize>,
+    /// Indices of the cases (see [CaseLog::cases])
+    pub cases: Vec<usize>,
+}
+
+///
+/// Group the cases of a [CaseLog] into variants
+///
+/// Variants are sorted by descending number of cases (ties keep the order of first occurrence)
+///
+pub fn variants(log: &CaseLog) -> Vec<Variant> {
+    let mut variant_indices: HashMap<&[usize], usize> = HashMap::new();
+    let mut variants: Vec<Variant> = Vec::new();
+    for (case_index, case) in log.cases.iter().enumerate() {
+        let index = *variant_indices
+            .entry(case.activities.as_slice())
+            .or_insert_with(|| {
+                variants.push(Variant {
+                    activities: case.activities.clone(),
+                    cases: Vec::new(),
+                });
+                variants.len() - 1
+            });
+        variants[index].cases.push(case_index);
+    }
+    variants.sort_by(|a, b| b.cases.len().cmp(&a.cases.len()));
+    variants
+}
+
+///
+/// Convert variants to a [DataFrame] with the columns `variant` (list of activities), `count`, `case_ids` (list),
+/// `mean_throughput_time` (in seconds; null if no case of the variant has timestamps) and `percentage` (of all cases)
+///
+pub fn variants_to_df(log: &CaseLog, variants: &[Variant]) -> PolarsResult<DataFrame> {
+    let num_cases = log.cases.len().max(1) as f64;
+    let variant_series: Vec<Series> = variants
+        .iter()
+        .map(|v| {
+            Series::new(
+                "".into(),
+                v.activities
+                    .iter()
+                    .map(|a| log.activities[*a].as_str())
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    let case_id_series: Vec<Series> = variants
+        .iter()
+        .map(|v| {
+            Series::new(
+                "".into(),
+                v.cases
+                    .iter()
+                    .map(|c| log.cases[*c].case_id.as_str())
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    let mean_throughput_times: Vec<Option<f64>> = variants
+        .iter()
+        .map(|v| {
+            let durations: Vec<f64> = v
+                .cases
+                .iter()
+                .filter_map(|c| log.cases[*c].duration())
+                .collect();
+            (!durations.is_empty())
+                .then(|| durations.iter().sum::<f64>() / durations.len() as f64)
+        })
+        .collect();
+    DataFrame::new(vec![
+        Column::new("variant".into(), variant_series),
+        Column::new(
+            "count".into(),
+            variants.iter().map(|v| v.cases.len() as u64).collect::<Vec<_>>(),
+        ),
+        Column::new("case_ids".into(), case_id_series),
+        Column::new("mean_throughput_time".into(), mean_throughput_times),
+        Column::new(
+            "percentage".into(),
+            variants
+                .iter()
+                .map(|v| 100.0 * v.cases.len() as f64 / num_cases)
+                .collect::<Vec<_>>(),
+        ),
+    ])
+}
+
+/// Selection of the most frequent variants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TopVariants {
+    /// The `k` most frequent variants
+    Count(usize),
+    /// The most frequent variants covering at least the given fraction of all cases
+    Coverage(f64),
+}
+
+///
+/// Filter an event [DataFrame] to the cases of the most frequent variants
+///
+pub fn filter_top_variants(
+    df: &DataFrame,
+    columns: &EventColumns,
+    top: TopVariants,
+) -> PolarsResult<DataFrame> {
+    let log = case_log_from_df(df, columns)?;
+    let variants = variants(&log);
+    let num_variants = match top {
+        TopVariants::Count(k) => k.min(variants.len()),
+        TopVariants::Coverage(coverage) => {
+            let min_cases = coverage * log.cases.len() as f64;
+            let mut covered = 0;
+            variants
+                .iter()
+                .take_while(|v| {
+                    let needed = (covered as f64) < min_cases;
+                    covered += v.cases.len();
+                    needed
+                })
+                .count()
+        }
+    };
+    let mut keep = vec![false; df.height()];
+    for variant in &variants[..num_variants] {
+        for case in &variant.cases {
+            for row in &log.cases[*case].rows {
+                keep[*row] = true;
+            }
+        }
+    }
+    df.filter(&BooleanChunked::from_slice("keep".into(), &keep))
+}
+
+///
+/// Compute the trace variants of an event log DataFrame
+///
+/// Returns a DataFrame with the columns `variant` (list of activities), `count`, `case_ids` (list), `mean_throughput_time` (in seconds)
+/// and `percentage` (of all cases), sorted by descending count
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+/// * `timestamp_col` - Optional timestamp column used for throughput times (default: `time:timestamp`)
+///
+#[pyfunction]
+#[pyo3(signature = (df, activity_col=None, case_col=None, timestamp_col=None))]
+pub fn variants_rs(
+    df: PyDataFrame,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+    timestamp_col: Option<String>,
+) -> PyResult<PyDataFrame> {
+    let columns = EventColumns::new(case_col, activity_col, timestamp_col);
+    let log = case_log_from_df(&df.0, &columns)
+        .map_err(|e| PyValueError::new_err(format!("Failed to read event log: {e:?}")))?;
+    let variants_df = variants_to_df(&log, &variants(&log))
+        .map_err(|e| PyValueError::new_err(format!("Failed to create DataFrame: {e:?}")))?;
+    Ok(PyDataFrame(variants_df))
+}
+
+///
+/// Filter an event log DataFrame to the cases of the most frequent variants
+///
+/// Exactly one of `k` and `coverage` has to be given
+///
+/// * `df` - The Polars DataFrame representing the event log (e.g., as returned by `import_xes_rs`)
+/// * `k` - Optional number of most frequent variants to keep
+/// * `coverage` - Optional minimal fraction of cases (between 0 and 1) covered by the kept (most frequent) variants
+/// * `activity_col` - Optional activity column (default: `concept:name`)
+/// * `case_col` - Optional case id column (default: `case:concept:name`)
+///
+#[pyfunction]
+#[pyo3(signature = (df, k=None, coverage=None, activity_col=None, case_col=None))]
+pub fn filter_top_variants_rs(
+    df: PyDataFrame,
+    k: Option<usize>,
+    coverage: Option<f64>,
+    activity_col: Option<String>,
+    case_col: Option<String>,
+) -> PyResult<PyDataFrame> {
+    let top = match (k, coverage) {
+        (Some(k), None) => TopVariants::Count(k),
+        (None, Some(coverage)) if (0.0..=1.0).contains(&coverage) => {
+            TopVariants::Coverage(coverage)
+        }
+        (None, Some(coverage)) => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid coverage {coverage} (expected a value between 0 and 1)"
+            )))
+        }
+        _ => {
+            return Err(PyValueError::new_err(
+                "Exactly one of 'k' and 'coverage' has to be given",
+            ))
+        }
+    };
+    let columns = EventColumns::new(case_col, activity_col, None);
+    let filtered = filter_top_variants(&df.0, &columns, top)
+        .map_err(|e| PyValueError::new_err(format!("Failed to filter variants: {e:?}")))?;
+    Ok(PyDataFrame(filtered))
+}
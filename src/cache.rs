@@ -0,0 +1,166 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use polars::prelude::*;
+use uuid::Uuid;
+
+/// Parquet key-value metadata key storing the cache key of a cached [DataFrame]
+pub const CACHE_KEY_METADATA_KEY: &str = "rustxes:cache_key";
+/// Parquet key-value metadata key storing the json-encoded log data of a cached XES log
+pub const LOG_DATA_METADATA_KEY: &str = "rustxes:log_data";
+
+/// 64-bit FNV-1a hash (stable across Rust releases and platforms, as required for keys persisted on disk)
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+///
+/// Compute the cache key of an imported file
+///
+/// The key has the form `<path hash>-<hash>`: the first part only depends on the (canonical) file path,
+/// the second part changes whenever the file path, its modification time or size, the import options (`options`)
+/// or the package version change.
+///
+pub fn cache_key(path: &str, options: &str) -> std::io::Result<String> {
+    let canonical_path = std::fs::canonicalize(path)?;
+    let metadata = std::fs::metadata(&canonical_path)?;
+    let mtime = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let canonical_path = canonical_path.to_string_lossy();
+    let key = format!(
+        "{canonical_path}\0{}\0{}\0{options}\0{}",
+        mtime.as_nanos(),
+        metadata.len(),
+        env!("CARGO_PKG_VERSION")
+    );
+    Ok(format!(
+        "{:016x}-{:016x}",
+        fnv1a_64(canonical_path.as_bytes()),
+        fnv1a_64(key.as_bytes())
+    ))
+}
+
+/// Path of a cached [DataFrame] in the cache directory
+pub fn cache_file_path(cache_dir: &str, key: &str, name: &str) -> PathBuf {
+    Path::new(cache_dir).join(format!("{key}.{name}.parquet"))
+}
+
+///
+/// Write a [DataFrame] to a Parquet file, including the given key-value metadata
+///
+/// The file is first written to a uniquely named temporary file and then renamed,
+/// so that readers never see partially written files (even with concurrent writers).
+///
+pub fn write_parquet_with_metadata(
+    path: &Path,
+    df: &mut DataFrame,
+    metadata: Vec<(String, String)>,
+) -> PolarsResult<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension(format!(
+        "parquet.{}.{}.tmp",
+        std::process::id(),
+        Uuid::new_v4().simple()
+    ));
+    let written = File::create(&tmp_path)
+        .map_err(PolarsError::from)
+        .and_then(|file| {
+            ParquetWriter::new(file)
+                .with_key_value_metadata(Some(KeyValueMetadata::from_static(metadata)))
+                .finish(df)?;
+            std::fs::rename(&tmp_path, path)?;
+            Ok(())
+        });
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+    written
+}
+
+///
+/// Read a [DataFrame] and its key-value metadata from a Parquet file
+///
+pub fn read_parquet_with_metadata(
+    path: &Path,
+) -> PolarsResult<(DataFrame, HashMap<String, String>)> {
+    let mut reader = ParquetReader::new(File::open(path)?);
+    let metadata = reader
+        .get_metadata()?
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .filter_map(|kv| kv.value.clone().map(|v| (kv.key.clone(), v)))
+        .collect();
+    let df = reader.finish()?;
+    Ok((df, metadata))
+}
+
+///
+/// Load a cached [DataFrame] (and its metadata)
+///
+/// Returns `None` if there is no (readable) cache entry for the key
+///
+pub fn load_cached_df(
+    cache_dir: &str,
+    key: &str,
+    name: &str,
+) -> Option<(DataFrame, HashMap<String, String>)> {
+    let path = cache_file_path(cache_dir, key, name);
+    if !path.exists() {
+        return None;
+    }
+    let (df, metadata) = read_parquet_with_metadata(&path).ok()?;
+    if metadata.get(CACHE_KEY_METADATA_KEY).map(String::as_str) != Some(key) {
+        return None;
+    }
+    Some((df, metadata))
+}
+
+///
+/// Store a [DataFrame] (and additional metadata) in the cache
+///
+/// Outdated cache entries of the same file (with the same path hash, see [cache_key]) and `name` are removed,
+/// so that changed files or import options do not accumulate copies in the cache directory.
+///
+pub fn store_cached_df(
+    cache_dir: &str,
+    key: &str,
+    name: &str,
+    df: &mut DataFrame,
+    mut metadata: Vec<(String, String)>,
+) -> PolarsResult<()> {
+    metadata.push((CACHE_KEY_METADATA_KEY.to_string(), key.to_string()));
+    let path = cache_file_path(cache_dir, key, name);
+    write_parquet_with_metadata(&path, df, metadata)?;
+    remove_outdated_entries(cache_dir, key, name, &path);
+    Ok(())
+}
+
+/// Remove the cache files of `name` for the same file as `key` (i.e., with the same path hash), except `current`
+fn remove_outdated_entries(cache_dir: &str, key: &str, name: &str, current: &Path) {
+    let Some((path_hash, _)) = key.split_once('-') else {
+        return;
+    };
+    let prefix = format!("{path_hash}-");
+    let suffix = format!(".{name}.parquet");
+    let Ok(entries) = std::fs::read_dir(cache_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if file_name.starts_with(&prefix) && file_name.ends_with(&suffix) && entry.path() != current {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use polars::prelude::*;
+use process_mining::{
+    convert_log_to_dataframe,
+    event_log::{Attribute, AttributeValue, Attributes, Event, EventLog, Trace},
+    export_xes_event_log_to_file_path,
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    pyfunction, PyResult,
+};
+use pyo3_polars::PyDataFrame;
+
+use crate::{
+    ocel::parse_timestamp,
+    parse_optional,
+    time::{apply_timezone, TimezoneOption},
+};
+
+/// Prefix of columns holding case (trace) attributes
+const CASE_PREFIX: &str = "case:";
+
+/// Mapping of the columns of a CSV file to the standard XES attributes
+#[derive(Debug, Clone)]
+pub struct CSVColumnMapping {
+    /// Column containing the case id (`case:concept:name`)
+    pub case_col: String,
+    /// Column containing the activity (`concept:name`)
+    pub activity_col: String,
+    /// Column containing the event timestamp (`time:timestamp`)
+    pub timestamp_col: String,
+    /// Optional column containing the resource (`org:resource`)
+    pub resource_col: Option<String>,
+    /// Columns containing case attributes (in addition to all columns starting with `case:`)
+    pub case_attribute_cols: Vec<String>,
+}
+
+impl CSVColumnMapping {
+    fn is_case_attribute(&self, col: &str) -> bool {
+        col.starts_with(CASE_PREFIX) || self.case_attribute_cols.iter().any(|c| c == col)
+    }
+}
+
+///
+/// Parse a timestamp of a CSV file
+///
+/// If a `format` is given (see https://docs.rs/chrono/latest/chrono/format/strftime/index.html), it is used for parsing;
+/// timestamps/dates without offset are interpreted as UTC.
+/// Otherwise, RFC 3339 timestamps as well as `%Y-%m-%d %H:%M:%S` timestamps and `%Y-%m-%d` dates are accepted.
+///
+pub fn parse_csv_timestamp(s: &str, format: Option<&str>) -> Result<DateTime<FixedOffset>, String> {
+    let Some(format) = format else {
+        return parse_timestamp(s.trim());
+    };
+    DateTime::parse_from_str(s, format)
+        .or_else(|_| NaiveDateTime::parse_from_str(s, format).map(|dt| dt.and_utc().fixed_offset()))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(s, format)
+                .map(|d| d.and_hms_opt(0, 0, 0).unwrap().and_utc().fixed_offset())
+        })
+        .map_err(|_| format!("Invalid timestamp '{s}' (expected format '{format}')"))
+}
+
+/// Convert a CSV cell to an XES [AttributeValue] (`None` for empty cells)
+fn any_value_to_xes_attribute_val(val: AnyValue) -> Option<AttributeValue> {
+    match val {
+        AnyValue::Null => None,
+        AnyValue::String(s) => Some(AttributeValue::String(s.to_string())),
+        AnyValue::StringOwned(s) => Some(AttributeValue::String(s.to_string())),
+        AnyValue::Boolean(b) => Some(AttributeValue::Boolean(b)),
+        AnyValue::Int32(i) => Some(AttributeValue::Int(i as i64)),
+        AnyValue::Int64(i) => Some(AttributeValue::Int(i)),
+        AnyValue::UInt32(i) => Some(AttributeValue::Int(i as i64)),
+        AnyValue::UInt64(i) => Some(AttributeValue::Int(i as i64)),
+        AnyValue::Float32(f) => Some(AttributeValue::Float(f as f64)),
+        AnyValue::Float64(f) => Some(AttributeValue::Float(f)),
+        v => Some(AttributeValue::String(v.to_string())),
+    }
+}
+
+/// Values of a column cast to strings
+fn string_column(df: &DataFrame, name: &str) -> Result<Vec<Option<String>>, String> {
+    let col = df
+        .column(name)
+        .map_err(|_| format!("Column '{name}' not found"))?
+        .cast(&DataType::String)
+        .map_err(|e| format!("Failed to read column '{name}': {e:?}"))?;
+    Ok(col
+        .str()
+        .map_err(|e| format!("Failed to read column '{name}': {e:?}"))?
+        .into_iter()
+        .map(|s| s.map(|s| s.to_string()))
+        .collect())
+}
+
+///
+/// Convert a DataFrame of a CSV file (one row per event) to an [EventLog]
+///
+/// Events are grouped into traces by the case column (in order of first occurrence) and sorted by their timestamps within each trace (stable).
+/// Case attributes are taken from the first event of each case; all other columns become event attributes.
+///
+pub fn csv_df_to_log(
+    df: &DataFrame,
+    mapping: &CSVColumnMapping,
+    timestamp_format: Option<&str>,
+) -> Result<EventLog, String> {
+    let case_ids = string_column(df, &mapping.case_col)?;
+    let activities = string_column(df, &mapping.activity_col)?;
+    let timestamps = string_column(df, &mapping.timestamp_col)?;
+    let resources = mapping
+        .resource_col
+        .as_deref()
+        .map(|c| string_column(df, c))
+        .transpose()?;
+    let mapped_cols = [
+        Some(&mapping.case_col),
+        Some(&mapping.activity_col),
+        Some(&mapping.timestamp_col),
+        mapping.resource_col.as_ref(),
+    ];
+    let other_series: Vec<Series> = df
+        .get_columns()
+        .iter()
+        .filter(|c| {
+            !mapped_cols
+                .iter()
+                .flatten()
+                .any(|m| m.as_str() == c.name().as_str())
+        })
+        .map(|c| c.as_materialized_series().rechunk())
+        .collect();
+    let other_values: Vec<(String, bool, Vec<AnyValue>)> = other_series
+        .iter()
+        .map(|s| {
+            let name = s.name().to_string();
+            let is_case_attribute = mapping.is_case_attribute(&name);
+            (name, is_case_attribute, s.iter().collect())
+        })
+        .collect();
+
+    let mut case_indices: HashMap<&str, usize> = HashMap::new();
+    let mut cases: Vec<(Attributes, Vec<(DateTime<FixedOffset>, Event)>)> = Vec::new();
+    for row in 0..df.height() {
+        let case_id = case_ids[row]
+            .as_deref()
+            .ok_or_else(|| format!("Missing case id in row {row}"))?;
+        let activity = activities[row]
+            .as_deref()
+            .ok_or_else(|| format!("Missing activity in row {row}"))?;
+        let time = timestamps[row]
+            .as_deref()
+            .ok_or_else(|| format!("Missing timestamp in row {row}"))
+            .and_then(|t| parse_csv_timestamp(t, timestamp_format))
+            .map_err(|e| format!("{e} in row {row}"))?;
+        let case_index = *case_indices.entry(case_id).or_insert_with(|| {
+            let mut attributes: Attributes = vec![Attribute::new(
+                "concept:name".to_string(),
+                AttributeValue::String(case_id.to_string()),
+            )];
+            attributes.extend(
+                other_values
+                    .iter()
+                    .filter(|(_, is_case_attribute, _)| *is_case_attribute)
+                    .filter_map(|(name, _, values)| {
+                        any_value_to_xes_attribute_val(values[row].clone()).map(|v| {
+                            Attribute::new(
+                                name.strip_prefix(CASE_PREFIX).unwrap_or(name).to_string(),
+                                v,
+                            )
+                        })
+                    }),
+            );
+            cases.push((attributes, Vec::new()));
+            cases.len() - 1
+        });
+        let mut attributes: Attributes = vec![
+            Attribute::new(
+                "concept:name".to_string(),
+                AttributeValue::String(activity.to_string()),
+            ),
+            Attribute::new("time:timestamp".to_string(), AttributeValue::Date(time)),
+        ];
+        if let Some(resource) = resources.as_ref().and_then(|r| r[row].as_ref()) {
+            attributes.push(Attribute::new(
+                "org:resource".to_string(),
+                AttributeValue::String(resource.clone()),
+            ));
+        }
+        attributes.extend(
+            other_values
+                .iter()
+                .filter(|(_, is_case_attribute, _)| !*is_case_attribute)
+                .filter_map(|(name, _, values)| {
+                    any_value_to_xes_attribute_val(values[row].clone())
+                        .map(|v| Attribute::new(name.clone(), v))
+                }),
+        );
+        cases[case_index].1.push((time, Event { attributes }));
+    }
+
+    let traces = cases
+        .into_iter()
+        .map(|(attributes, mut events)| {
+            events.sort_by_key(|(time, _)| *time);
+            Trace {
+                attributes,
+                events: events.into_iter().map(|(_, e)| e).collect(),
+            }
+        })
+        .collect();
+    Ok(EventLog {
+        attributes: Vec::new(),
+        traces,
+        extensions: None,
+        classifiers: None,
+        global_trace_attrs: None,
+        global_event_attrs: None,
+    })
+}
+
+///
+/// Read a CSV file (with header) into a [DataFrame]
+///
+pub fn read_csv(path: &str, separator: u8) -> PolarsResult<DataFrame> {
+    CsvReadOptions::default()
+        .with_has_header(true)
+        .with_parse_options(CsvParseOptions::default().with_separator(separator))
+        .try_into_reader_with_file_path(Some(path.into()))?
+        .finish()
+}
+
+///
+/// Import a case-centric event log from a CSV file (one row per event)
+///
+/// Returns a Polars [DataFrame] with the same schema as `import_xes_rs`
+///
+/// * `path` - The filepath of the .csv file to import
+/// * `case_col` - Column containing the case id (becomes `case:concept:name`)
+/// * `activity_col` - Column containing the activity (becomes `concept:name`)
+/// * `timestamp_col` - Column containing the event timestamp (becomes `time:timestamp`)
+/// * `timestamp_format` - Optional format of the timestamps (See https://docs.rs/chrono/latest/chrono/format/strftime/index.html); timestamps without offset are interpreted as UTC
+/// * `separator` - Optional column separator (default: `,`)
+/// * `resource_col` - Optional column containing the resource (becomes `org:resource`)
+/// * `case_attribute_cols` - Optional columns containing case attributes (in addition to all columns starting with `case:`)
+/// * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` or a named timezone (e.g., `"Europe/Berlin"`)
+/// * `xes_path` - Optional filepath of a .xes or .xes.gz file the imported log should be exported to
+///
+#[pyfunction]
+#[pyo3(signature = (path, case_col, activity_col, timestamp_col, timestamp_format=None, separator=None, resource_col=None, case_attribute_cols=None, timezone=None, xes_path=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn import_csv_log_rs(
+    path: String,
+    case_col: String,
+    activity_col: String,
+    timestamp_col: String,
+    timestamp_format: Option<String>,
+    separator: Option<String>,
+    resource_col: Option<String>,
+    case_attribute_cols: Option<Vec<String>>,
+    timezone: Option<String>,
+    xes_path: Option<String>,
+) -> PyResult<PyDataFrame> {
+    let timezone: Option<TimezoneOption> = parse_optional(timezone)?;
+    let separator = match separator.as_deref().map(str::as_bytes) {
+        None => b',',
+        Some([b]) => *b,
+        Some(_) => {
+            return Err(PyValueError::new_err(
+                "Separator must be a single ASCII character",
+            ))
+        }
+    };
+    let df = read_csv(&path, separator)
+        .map_err(|e| PyValueError::new_err(format!("Failed to read CSV: {e:?}")))?;
+    let mapping = CSVColumnMapping {
+        case_col,
+        activity_col,
+        timestamp_col,
+        resource_col,
+        case_attribute_cols: case_attribute_cols.unwrap_or_default(),
+    };
+    let log = csv_df_to_log(&df, &mapping, timestamp_format.as_deref())
+        .map_err(PyValueError::new_err)?;
+    if let Some(xes_path) = xes_path {
+        export_xes_event_log_to_file_path(&log, xes_path)
+            .map_err(|e| PyTypeError::new_err(format!("Failed to export XES: {e:?}")))?;
+    }
+    let mut df = convert_log_to_dataframe(&log, false)
+        .map_err(|e| PyTypeError::new_err(format!("Failed to convert log to dataframe: {e:?}")))?;
+    if let Some(timezone) = timezone {
+        apply_timezone(&mut df, &timezone)
+            .map_err(|e| PyValueError::new_err(format!("Failed to apply timezone: {e:?}")))?;
+    }
+    Ok(PyDataFrame(df))
+}
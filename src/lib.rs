@@ -1,16 +1,87 @@
-use std::time::Instant;
+use std::{ffi::CString, str::FromStr, time::Instant};
 
 use process_mining::{
-    convert_dataframe_to_log, convert_log_to_dataframe, event_log::stream_xes::XESOuterLogData,
+    convert_dataframe_to_log, convert_log_to_dataframe,
+    event_log::{stream_xes::XESOuterLogData, EventLog},
     export_xes_event_log_to_file_path, import_xes_file, XESImportOptions,
 };
-use pyo3::{exceptions::PyTypeError, prelude::*};
+use pyo3::{
+    exceptions::{PyTypeError, PyUserWarning, PyValueError},
+    prelude::*,
+};
 use pyo3_polars::PyDataFrame;
+use time::{apply_timezone, TimezoneOption};
 
-use crate::ocel::{import_ocel_json_rs, import_ocel_xml_rs};
+use crate::analysis::{
+    alignments::alignments_rs,
+    alpha::discover_alpha_rs,
+    dfg::discover_dfg_rs,
+    inductive::discover_inductive_rs,
+    summary::log_summary_rs,
+    token_replay::token_replay_rs,
+    variants::{filter_top_variants_rs, variants_rs},
+};
+use crate::cache::{cache_key, load_cached_df, store_cached_df, LOG_DATA_METADATA_KEY};
+use crate::csv_log::import_csv_log_rs;
+use crate::mxml::import_mxml_rs;
+use crate::pnml::{export_pnml_rs, import_pnml_rs};
+use crate::process_tree::process_tree_to_petri_net_rs;
+use crate::ocel::{
+    export_ocel_rs,
+    filter::slice_ocel_rs,
+    flatten::flatten_ocel_rs,
+    from_xes::xes_to_ocel_rs,
+    import_ocel_json_rs, import_ocel_xml_rs,
+    ocdfg::discover_ocdfg_rs,
+    parquet::{read_ocel_parquet_rs, write_ocel_parquet_dfs_rs, write_ocel_parquet_path_rs},
+    snapshot::{enrich_events_with_object_state_rs, object_snapshot_rs},
+    summary::ocel_summary_rs,
+    validate::{validate_ocel_dfs_rs, validate_ocel_path_rs},
+};
+use crate::xes_json::{export_xes_json_rs, import_xes_json_rs};
 
+mod analysis;
+mod cache;
+mod csv_log;
+mod mxml;
 mod ocel;
+mod petri_net;
+mod pnml;
+mod process_tree;
 mod test;
+mod time;
+mod xes_json;
+
+/// Parse an optional option string passed from Python
+pub(crate) fn parse_optional<T: FromStr<Err = String>>(value: Option<String>) -> PyResult<Option<T>> {
+    value
+        .as_deref()
+        .map(T::from_str)
+        .transpose()
+        .map_err(PyValueError::new_err)
+}
+
+/// Parse an optional option string passed from Python (using the default if `None`)
+pub(crate) fn parse_option<T: FromStr<Err = String> + Default>(value: Option<String>) -> PyResult<T> {
+    parse_optional(value).map(Option::unwrap_or_default)
+}
+
+/// Emit a Python `UserWarning` (fails if warnings are turned into errors, e.g., with `-W error`)
+pub(crate) fn warn_py(message: &str) -> PyResult<()> {
+    let message = CString::new(message).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Python::with_gil(|py| PyErr::warn(py, &py.get_type::<PyUserWarning>(), &message, 1))
+}
+
+/// Log attributes/extensions/classifiers of an [EventLog] (returned as json-encoding alongside the event data)
+pub(crate) fn outer_log_data(log: &EventLog) -> XESOuterLogData {
+    XESOuterLogData {
+        log_attributes: log.attributes.clone(),
+        extensions: log.extensions.clone().unwrap_or_default(),
+        classifiers: log.classifiers.clone().unwrap_or_default(),
+        global_trace_attrs: log.global_trace_attrs.clone().unwrap_or_default(),
+        global_event_attrs: log.global_event_attrs.clone().unwrap_or_default(),
+    }
+}
 
 ///
 /// Import an XES event log
@@ -20,15 +91,35 @@ mod test;
 /// * `path` - The filepath of the .xes or .xes.gz file to import
 /// * `date_format` - Optional date format to use for parsing <date> tags (See https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
 /// * `print_debug` - Optional flag to enable debug print outputs
+/// * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` or a named timezone (e.g., `"Europe/Berlin"`)
+/// * `cache_dir` - Optional directory for caching the imported log as Parquet (keyed on file path, modification time, size and import options)
 ///
 ///
 #[pyfunction]
-#[pyo3(signature = (path, date_format=None, print_debug=None))]
+#[pyo3(signature = (path, date_format=None, print_debug=None, timezone=None, cache_dir=None))]
 fn import_xes_rs(
     path: String,
     date_format: Option<String>,
     print_debug: Option<bool>,
+    timezone: Option<String>,
+    cache_dir: Option<String>,
 ) -> PyResult<(PyDataFrame, String)> {
+    let timezone: Option<TimezoneOption> = parse_optional(timezone)?;
+    let cache_key = cache_dir
+        .as_ref()
+        .map(|_| cache_key(&path, &format!("xes:{date_format:?}:{timezone:?}")))
+        .transpose()
+        .map_err(|e| PyValueError::new_err(format!("Failed to access {path}: {e}")))?;
+    if let (Some(cache_dir), Some(key)) = (&cache_dir, &cache_key) {
+        if let Some((df, mut metadata)) = load_cached_df(cache_dir, key, "xes") {
+            if let Some(log_data) = metadata.remove(LOG_DATA_METADATA_KEY) {
+                if print_debug.is_some_and(|a| a) {
+                    println!("Loaded XES Log from cache");
+                }
+                return Ok((PyDataFrame(df), log_data));
+            }
+        }
+    }
     if print_debug.is_some_and(|a| a) {
         println!("Starting XES Import");
     }
@@ -46,24 +137,32 @@ fn import_xes_rs(
         println!("Importing XES Log took {:.2?}", now.elapsed());
     }
     now = Instant::now();
-    let other_data = XESOuterLogData {
-        log_attributes: log.attributes.clone(),
-        extensions: log.extensions.clone().unwrap_or_default().clone(),
-        classifiers: log.classifiers.clone().unwrap_or_default().clone(),
-        global_trace_attrs: log.global_trace_attrs.clone().unwrap_or_default(),
-        global_event_attrs: log.global_event_attrs.clone().unwrap_or_default(),
-    };
-    let converted_log = convert_log_to_dataframe(&log, print_debug.unwrap_or_default()).unwrap();
+    let other_data = outer_log_data(&log);
+    let mut converted_log =
+        convert_log_to_dataframe(&log, print_debug.unwrap_or_default()).unwrap();
+    if let Some(timezone) = timezone {
+        apply_timezone(&mut converted_log, &timezone)
+            .map_err(|e| PyValueError::new_err(format!("Failed to apply timezone: {e:?}")))?;
+    }
     if print_debug.is_some_and(|a| a) {
         println!("Finished Converting Log; Took {:.2?}", now.elapsed());
     }
     if print_debug.is_some_and(|a| a) {
         println!("Total duration: {:.2?}", start_now.elapsed());
     }
-    Ok((
-        PyDataFrame(converted_log),
-        serde_json::to_string(&other_data).unwrap(),
-    ))
+    let log_data = serde_json::to_string(&other_data).unwrap();
+    if let (Some(cache_dir), Some(key)) = (&cache_dir, &cache_key) {
+        if let Err(e) = store_cached_df(
+            cache_dir,
+            key,
+            "xes",
+            &mut converted_log,
+            vec![(LOG_DATA_METADATA_KEY.to_string(), log_data.clone())],
+        ) {
+            warn_py(&format!("Failed to write cache: {e}"))?;
+        }
+    }
+    Ok((PyDataFrame(converted_log), log_data))
 }
 
 #[pyfunction]
@@ -82,7 +181,35 @@ fn export_xes_rs(df: PyDataFrame, path: String) -> PyResult<()> {
 fn rustxes(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(import_xes_rs, m)?)?;
     m.add_function(wrap_pyfunction!(export_xes_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(import_csv_log_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(import_mxml_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(import_xes_json_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(export_xes_json_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(discover_dfg_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(discover_ocdfg_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(variants_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(filter_top_variants_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(log_summary_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(ocel_summary_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(discover_alpha_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(discover_inductive_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(process_tree_to_petri_net_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(token_replay_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(alignments_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(import_pnml_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(export_pnml_rs, m)?)?;
     m.add_function(wrap_pyfunction!(import_ocel_xml_rs, m)?)?;
     m.add_function(wrap_pyfunction!(import_ocel_json_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(object_snapshot_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(enrich_events_with_object_state_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_ocel_path_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_ocel_dfs_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(slice_ocel_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(flatten_ocel_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(xes_to_ocel_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(export_ocel_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(write_ocel_parquet_path_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(write_ocel_parquet_dfs_rs, m)?)?;
+    m.add_function(wrap_pyfunction!(read_ocel_parquet_rs, m)?)?;
     Ok(())
 }
@@ -0,0 +1,222 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use chrono::{DateTime, FixedOffset};
+use flate2::read::GzDecoder;
+use process_mining::{
+    convert_log_to_dataframe,
+    event_log::{Attribute, AttributeValue, Attributes, Event, EventLog, Trace},
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    pyfunction, PyResult,
+};
+use pyo3_polars::PyDataFrame;
+use quick_xml::events::{BytesStart, Event as XMLEvent};
+
+use crate::{
+    ocel::parse_timestamp,
+    outer_log_data, parse_optional,
+    time::{apply_timezone, TimezoneOption},
+};
+
+///
+/// Parse an MXML timestamp
+///
+/// Accepts RFC 3339 timestamps and timestamps with an offset without colon (e.g., `2010-12-30T11:02:00.000+0100`);
+/// timestamps without offset are interpreted as UTC.
+///
+pub fn parse_mxml_timestamp(s: &str) -> Result<DateTime<FixedOffset>, String> {
+    let s = s.trim();
+    parse_timestamp(s).or_else(|e| {
+        DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f%z").map_err(|_| e)
+    })
+}
+
+/// Value of an attribute of an MXML element
+fn xml_attr(e: &BytesStart, key: &[u8]) -> Result<Option<String>, String> {
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| format!("Invalid XML attribute: {e}"))?;
+        if attr.key.as_ref() == key {
+            return attr
+                .unescape_value()
+                .map(|v| Some(v.to_string()))
+                .map_err(|e| format!("Invalid XML attribute value: {e}"));
+        }
+    }
+    Ok(None)
+}
+
+fn new_trace(id: Option<String>) -> Trace {
+    Trace {
+        attributes: id
+            .map(|id| vec![Attribute::new("concept:name".to_string(), AttributeValue::String(id))])
+            .unwrap_or_default(),
+        events: Vec::new(),
+    }
+}
+
+/// State of the MXML parser
+#[derive(Default)]
+struct MXMLParser {
+    log_attributes: Attributes,
+    traces: Vec<Trace>,
+    current_trace: Option<Trace>,
+    current_event: Option<Attributes>,
+    /// Name of the `Data` attribute or standard element (e.g., `Timestamp`) whose text is currently read
+    current_key: Option<String>,
+    text: String,
+    /// Non-standard event type (stored in the `unknowntype` attribute of `EventType`)
+    unknown_event_type: Option<String>,
+}
+
+impl MXMLParser {
+    fn start_element(&mut self, e: &BytesStart) -> Result<(), String> {
+        match e.name().as_ref() {
+            b"ProcessInstance" => self.current_trace = Some(new_trace(xml_attr(e, b"id")?)),
+            b"AuditTrailEntry" => self.current_event = Some(Vec::new()),
+            b"Attribute" => self.current_key = xml_attr(e, b"name")?,
+            b"EventType" => {
+                self.current_key = Some("EventType".to_string());
+                self.unknown_event_type = xml_attr(e, b"unknowntype")?;
+            }
+            b"WorkflowModelElement" | b"Timestamp" | b"Originator" => {
+                self.current_key = Some(String::from_utf8_lossy(e.name().as_ref()).to_string())
+            }
+            _ => {}
+        }
+        self.text.clear();
+        Ok(())
+    }
+
+    fn end_element(&mut self, name: &[u8]) -> Result<(), String> {
+        match name {
+            b"AuditTrailEntry" => {
+                if let (Some(trace), Some(attributes)) =
+                    (self.current_trace.as_mut(), self.current_event.take())
+                {
+                    trace.events.push(Event { attributes });
+                }
+            }
+            b"ProcessInstance" => {
+                if let Some(trace) = self.current_trace.take() {
+                    self.traces.push(trace);
+                }
+            }
+            b"Attribute" | b"WorkflowModelElement" | b"EventType" | b"Timestamp"
+            | b"Originator" => {
+                let Some(key) = self.current_key.take() else {
+                    return Ok(());
+                };
+                let value = std::mem::take(&mut self.text);
+                let attribute = match (name, key.as_str()) {
+                    (b"Attribute", _) => Attribute::new(key, AttributeValue::String(value)),
+                    (_, "WorkflowModelElement") => {
+                        Attribute::new("concept:name".to_string(), AttributeValue::String(value))
+                    }
+                    (_, "EventType") => Attribute::new(
+                        "lifecycle:transition".to_string(),
+                        AttributeValue::String(self.unknown_event_type.take().unwrap_or(value)),
+                    ),
+                    (_, "Timestamp") => Attribute::new(
+                        "time:timestamp".to_string(),
+                        AttributeValue::Date(parse_mxml_timestamp(&value)?),
+                    ),
+                    _ => Attribute::new("org:resource".to_string(), AttributeValue::String(value)),
+                };
+                if let Some(event) = self.current_event.as_mut() {
+                    event.push(attribute);
+                } else if let Some(trace) = self.current_trace.as_mut() {
+                    trace.attributes.push(attribute);
+                } else {
+                    self.log_attributes.push(attribute);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+///
+/// Parse an MXML event log
+///
+/// Every `ProcessInstance` becomes a trace (with its `id` as `concept:name`) and every `AuditTrailEntry` an event.
+/// `WorkflowModelElement`, `EventType`, `Timestamp` and `Originator` are mapped to
+/// `concept:name`, `lifecycle:transition`, `time:timestamp` and `org:resource`.
+/// `Data` attributes are kept as string attributes of the log, trace or event they belong to.
+///
+pub fn parse_mxml<R: BufRead>(reader: R) -> Result<EventLog, String> {
+    let mut reader = quick_xml::Reader::from_reader(reader);
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+    let mut parser = MXMLParser::default();
+    loop {
+        match reader
+            .read_event_into(&mut buf)
+            .map_err(|e| format!("Invalid XML: {e}"))?
+        {
+            XMLEvent::Start(e) => parser.start_element(&e)?,
+            XMLEvent::Empty(e) => {
+                parser.start_element(&e)?;
+                parser.end_element(e.name().as_ref())?;
+            }
+            XMLEvent::Text(t) => parser.text.push_str(
+                &t.unescape()
+                    .map_err(|e| format!("Invalid XML text: {e}"))?,
+            ),
+            XMLEvent::CData(t) => parser.text.push_str(&String::from_utf8_lossy(&t)),
+            XMLEvent::End(e) => parser.end_element(e.name().as_ref())?,
+            XMLEvent::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(EventLog {
+        attributes: parser.log_attributes,
+        traces: parser.traces,
+        extensions: None,
+        classifiers: None,
+        global_trace_attrs: None,
+        global_event_attrs: None,
+    })
+}
+
+///
+/// Import an MXML file (`.mxml` or `.mxml.gz`)
+///
+pub fn import_mxml_file(path: &str) -> Result<EventLog, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {path}: {e}"))?;
+    if path.ends_with(".gz") {
+        parse_mxml(BufReader::new(GzDecoder::new(file)))
+    } else {
+        parse_mxml(BufReader::new(file))
+    }
+}
+
+///
+/// Import an MXML event log
+///
+/// Returns a tuple of a Polars [DataFrame] with the same layout as `import_xes_rs` and a json-encoding of all log attributes
+///
+/// * `path` - The filepath of the .mxml or .mxml.gz file to import
+/// * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` or a named timezone (e.g., `"Europe/Berlin"`)
+///
+#[pyfunction]
+#[pyo3(signature = (path, timezone=None))]
+pub fn import_mxml_rs(path: String, timezone: Option<String>) -> PyResult<(PyDataFrame, String)> {
+    let timezone: Option<TimezoneOption> = parse_optional(timezone)?;
+    let log = import_mxml_file(&path).map_err(PyValueError::new_err)?;
+    let mut df = convert_log_to_dataframe(&log, false)
+        .map_err(|e| PyTypeError::new_err(format!("Failed to convert log to dataframe: {e:?}")))?;
+    if let Some(timezone) = timezone {
+        apply_timezone(&mut df, &timezone)
+            .map_err(|e| PyValueError::new_err(format!("Failed to apply timezone: {e:?}")))?;
+    }
+    Ok((
+        PyDataFrame(df),
+        serde_json::to_string(&outer_log_data(&log)).unwrap(),
+    ))
+}
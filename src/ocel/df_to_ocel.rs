@@ -0,0 +1,310 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, FixedOffset};
+use polars::prelude::*;
+use process_mining::{
+    ocel::ocel_struct::{
+        OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
+        OCELRelationship, OCELType, OCELTypeAttribute,
+    },
+    OCEL,
+};
+
+use crate::analysis::{column_ids, column_strings};
+
+use super::{
+    OCEL2DataFrames, OCEL_CHANGED_FIELD_KEY, OCEL_EVENT_ID_KEY, OCEL_EVENT_TIMESTAMP_KEY,
+    OCEL_EVENT_TYPE_KEY, OCEL_OBJECT_ID_2_KEY, OCEL_OBJECT_ID_KEY, OCEL_OBJECT_TYPE_KEY,
+    OCEL_QUALIFIER_KEY, OCEL_VALUE_COLUMNS,
+};
+
+/// Convert a timestamp with the given [TimeUnit] to a (UTC) [DateTime]
+///
+/// Returns `None` if the timestamp is out of the range representable by [DateTime]
+pub fn timestamp_to_datetime(value: i64, unit: TimeUnit) -> Option<DateTime<FixedOffset>> {
+    let dt = match unit {
+        TimeUnit::Nanoseconds => Some(DateTime::from_timestamp_nanos(value)),
+        TimeUnit::Microseconds => DateTime::from_timestamp_micros(value),
+        TimeUnit::Milliseconds => DateTime::from_timestamp_millis(value),
+    };
+    dt.map(|dt| dt.fixed_offset())
+}
+
+/// Convert a Polars [AnyValue] back to an [OCELAttributeValue]
+///
+/// Values of types without an OCEL counterpart are converted to their string representation
+pub fn any_value_to_ocel_attribute_val(val: AnyValue) -> OCELAttributeValue {
+    match val {
+        AnyValue::Null => OCELAttributeValue::Null,
+        AnyValue::String(s) => OCELAttributeValue::String(s.to_string()),
+        AnyValue::StringOwned(s) => OCELAttributeValue::String(s.to_string()),
+        AnyValue::Boolean(b) => OCELAttributeValue::Boolean(b),
+        AnyValue::Int8(i) => OCELAttributeValue::Integer(i.into()),
+        AnyValue::Int16(i) => OCELAttributeValue::Integer(i.into()),
+        AnyValue::Int32(i) => OCELAttributeValue::Integer(i.into()),
+        AnyValue::Int64(i) => OCELAttributeValue::Integer(i),
+        AnyValue::UInt8(i) => OCELAttributeValue::Integer(i.into()),
+        AnyValue::UInt16(i) => OCELAttributeValue::Integer(i.into()),
+        AnyValue::UInt32(i) => OCELAttributeValue::Integer(i.into()),
+        AnyValue::UInt64(i) => OCELAttributeValue::Integer(i as i64),
+        AnyValue::Float32(f) => OCELAttributeValue::Float(f.into()),
+        AnyValue::Float64(f) => OCELAttributeValue::Float(f),
+        AnyValue::Datetime(v, unit, _) => timestamp_to_datetime(v, unit)
+            .map(OCELAttributeValue::Time)
+            .unwrap_or(OCELAttributeValue::Null),
+        AnyValue::DatetimeOwned(v, unit, _) => timestamp_to_datetime(v, unit)
+            .map(OCELAttributeValue::Time)
+            .unwrap_or(OCELAttributeValue::Null),
+        other => OCELAttributeValue::String(other.to_string()),
+    }
+}
+
+/// Name of the OCEL attribute type of an [OCELAttributeValue] (`None` for [OCELAttributeValue::Null])
+pub fn ocel_attribute_type_name(val: &OCELAttributeValue) -> Option<&'static str> {
+    match val {
+        OCELAttributeValue::String(_) => Some("string"),
+        OCELAttributeValue::Time(_) => Some("time"),
+        OCELAttributeValue::Integer(_) => Some("integer"),
+        OCELAttributeValue::Float(_) => Some("float"),
+        OCELAttributeValue::Boolean(_) => Some("boolean"),
+        OCELAttributeValue::Null => None,
+    }
+}
+
+fn column_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<OCELAttributeValue>> {
+    Ok(df
+        .column(name)?
+        .as_materialized_series()
+        .rechunk()
+        .iter()
+        .map(any_value_to_ocel_attribute_val)
+        .collect())
+}
+
+/// Timestamps of a datetime column (fails for null or non-datetime values, naming the column and row)
+fn column_times(df: &DataFrame, name: &str) -> PolarsResult<Vec<DateTime<FixedOffset>>> {
+    column_values(df, name)?
+        .into_iter()
+        .enumerate()
+        .map(|(row, v)| match v {
+            OCELAttributeValue::Time(t) => Ok(t),
+            OCELAttributeValue::Null => {
+                polars_bail!(ComputeError: "Column '{}' has a null timestamp in row {}", name, row)
+            }
+            other => polars_bail!(
+                ComputeError: "Column '{}' has a value of type {} in row {} (expected datetime)",
+                name,
+                ocel_attribute_type_name(&other).unwrap_or("null"),
+                row
+            ),
+        })
+        .collect()
+}
+
+/// Derive type declarations from the observed (non-null) attribute values per type
+pub(super) fn types_from_attributes<'a>(
+    type_names: impl Iterator<Item = &'a String>,
+    attributes: impl Iterator<Item = (&'a String, &'a String, &'a OCELAttributeValue)>,
+) -> Vec<OCELType> {
+    let mut type_attrs: HashMap<&String, Vec<(&String, &'static str)>> =
+        type_names.map(|t| (t, Vec::new())).collect();
+    let mut seen: HashSet<(&String, &String)> = HashSet::new();
+    for (type_name, attr_name, value) in attributes {
+        if let Some(value_type) = ocel_attribute_type_name(value) {
+            if seen.insert((type_name, attr_name)) {
+                type_attrs
+                    .entry(type_name)
+                    .or_default()
+                    .push((attr_name, value_type));
+            }
+        }
+    }
+    let mut types: Vec<OCELType> = type_attrs
+        .into_iter()
+        .map(|(name, attrs)| OCELType {
+            name: name.clone(),
+            attributes: attrs
+                .into_iter()
+                .map(|(attr_name, value_type)| OCELTypeAttribute {
+                    name: attr_name.clone(),
+                    value_type: value_type.to_string(),
+                })
+                .collect(),
+        })
+        .collect();
+    types.sort_by(|a, b| a.name.cmp(&b.name));
+    types
+}
+
+///
+/// Reconstruct an [OCEL] from its [OCEL2DataFrames] representation
+///
+/// The `object_changes` DataFrame may be in either the wide or long format (see [super::ObjectChangesFormat])
+/// and is used as the source of all object attribute values.
+/// Null values in id, type or field columns are rejected (null qualifiers become empty strings).
+/// Type declarations are derived from the attribute values observed for each event/object type.
+///
+pub fn ocel_dfs_to_ocel(dfs: &OCEL2DataFrames) -> PolarsResult<OCEL> {
+    // Events
+    let ev_ids = column_ids(&dfs.events, OCEL_EVENT_ID_KEY)?;
+    let ev_types = column_ids(&dfs.events, OCEL_EVENT_TYPE_KEY)?;
+    let ev_times = column_times(&dfs.events, OCEL_EVENT_TIMESTAMP_KEY)?;
+    let ev_attr_names: Vec<String> = dfs
+        .events
+        .get_column_names()
+        .into_iter()
+        .map(|n| n.to_string())
+        .filter(|n| {
+            n != OCEL_EVENT_ID_KEY && n != OCEL_EVENT_TYPE_KEY && n != OCEL_EVENT_TIMESTAMP_KEY
+        })
+        .collect();
+    let ev_attr_values = ev_attr_names
+        .iter()
+        .map(|n| column_values(&dfs.events, n))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut events: Vec<OCELEvent> = ev_ids
+        .into_iter()
+        .zip(ev_types)
+        .zip(ev_times)
+        .enumerate()
+        .map(|(i, ((id, event_type), time))| OCELEvent {
+            id,
+            event_type,
+            time,
+            attributes: ev_attr_names
+                .iter()
+                .zip(&ev_attr_values)
+                .filter(|(_name, values)| !matches!(values[i], OCELAttributeValue::Null))
+                .map(|(name, values)| OCELEventAttribute {
+                    name: name.clone(),
+                    value: values[i].clone(),
+                })
+                .collect(),
+            relationships: Vec::new(),
+        })
+        .collect();
+    let ev_index: HashMap<String, usize> = events
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (e.id.clone(), i))
+        .collect();
+
+    // E2O
+    let e2o_ev_ids = column_ids(&dfs.e2o, OCEL_EVENT_ID_KEY)?;
+    let e2o_obj_ids = column_ids(&dfs.e2o, OCEL_OBJECT_ID_KEY)?;
+    let e2o_qualifiers = column_strings(&dfs.e2o, OCEL_QUALIFIER_KEY)?;
+    for ((ev_id, object_id), qualifier) in e2o_ev_ids.into_iter().zip(e2o_obj_ids).zip(e2o_qualifiers)
+    {
+        if let Some(i) = ev_index.get(&ev_id) {
+            events[*i].relationships.push(OCELRelationship {
+                object_id,
+                qualifier,
+            });
+        }
+    }
+
+    // Objects
+    let obj_ids = column_ids(&dfs.objects, OCEL_OBJECT_ID_KEY)?;
+    let obj_types = column_ids(&dfs.objects, OCEL_OBJECT_TYPE_KEY)?;
+    let mut objects: Vec<OCELObject> = obj_ids
+        .into_iter()
+        .zip(obj_types)
+        .map(|(id, object_type)| OCELObject {
+            id,
+            object_type,
+            attributes: Vec::new(),
+            relationships: Vec::new(),
+        })
+        .collect();
+    let obj_index: HashMap<String, usize> = objects
+        .iter()
+        .enumerate()
+        .map(|(i, o)| (o.id.clone(), i))
+        .collect();
+
+    // Object attribute values
+    let oc_obj_ids = column_ids(&dfs.object_changes, OCEL_OBJECT_ID_KEY)?;
+    let oc_fields = column_ids(&dfs.object_changes, OCEL_CHANGED_FIELD_KEY)?;
+    let oc_times = column_times(&dfs.object_changes, OCEL_EVENT_TIMESTAMP_KEY)?;
+    let oc_columns: HashSet<String> = dfs
+        .object_changes
+        .get_column_names()
+        .into_iter()
+        .map(|n| n.to_string())
+        .collect();
+    let is_long_format = OCEL_VALUE_COLUMNS.iter().all(|c| oc_columns.contains(*c));
+    let value_columns: Vec<&str> = if is_long_format {
+        OCEL_VALUE_COLUMNS.to_vec()
+    } else {
+        oc_fields
+            .iter()
+            .map(|f| f.as_str())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter(|f| oc_columns.contains(*f))
+            .collect()
+    };
+    let value_columns: HashMap<&str, Vec<OCELAttributeValue>> = value_columns
+        .into_iter()
+        .map(|c| Ok((c, column_values(&dfs.object_changes, c)?)))
+        .collect::<PolarsResult<_>>()?;
+    for (i, ((obj_id, field), time)) in oc_obj_ids
+        .into_iter()
+        .zip(oc_fields.iter())
+        .zip(oc_times)
+        .enumerate()
+    {
+        let value = if is_long_format {
+            OCEL_VALUE_COLUMNS
+                .iter()
+                .map(|c| &value_columns[c][i])
+                .find(|v| !matches!(v, OCELAttributeValue::Null))
+                .cloned()
+                .unwrap_or(OCELAttributeValue::Null)
+        } else {
+            value_columns
+                .get(field.as_str())
+                .map(|values| values[i].clone())
+                .unwrap_or(OCELAttributeValue::Null)
+        };
+        if let Some(o) = obj_index.get(&obj_id) {
+            objects[*o].attributes.push(OCELObjectAttribute {
+                name: field.clone(),
+                value,
+                time,
+            });
+        }
+    }
+
+    // O2O
+    let o2o_from = column_ids(&dfs.o2o, OCEL_OBJECT_ID_KEY)?;
+    let o2o_to = column_ids(&dfs.o2o, OCEL_OBJECT_ID_2_KEY)?;
+    let o2o_qualifiers = column_strings(&dfs.o2o, OCEL_QUALIFIER_KEY)?;
+    for ((from, object_id), qualifier) in o2o_from.into_iter().zip(o2o_to).zip(o2o_qualifiers) {
+        if let Some(o) = obj_index.get(&from) {
+            objects[*o].relationships.push(OCELRelationship {
+                object_id,
+                qualifier,
+            });
+        }
+    }
+
+    let event_types = types_from_attributes(
+        events.iter().map(|e| &e.event_type),
+        events
+            .iter()
+            .flat_map(|e| e.attributes.iter().map(move |a| (&e.event_type, &a.name, &a.value))),
+    );
+    let object_types = types_from_attributes(
+        objects.iter().map(|o| &o.object_type),
+        objects
+            .iter()
+            .flat_map(|o| o.attributes.iter().map(move |a| (&o.object_type, &a.name, &a.value))),
+    );
+    Ok(OCEL {
+        event_types,
+        object_types,
+        events,
+        objects,
+    })
+}
@@ -0,0 +1,259 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use chrono::{DateTime, FixedOffset};
+use process_mining::OCEL;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::{parse_option, parse_optional};
+
+use super::{
+    infer_df_options, ocel2_to_df_with, ocel_dfs_from_py, ocel_dfs_to_ocel, ocel_dfs_to_py,
+    parse_timestamp,
+};
+
+/// Include/exclude filter on the event and object types of an [OCEL]
+#[derive(Debug, Clone, Default)]
+pub struct OCELTypeFilter {
+    /// If set, only objects of these types are kept
+    pub object_types: Option<HashSet<String>>,
+    /// Objects of these types are removed
+    pub exclude_object_types: HashSet<String>,
+    /// If set, only events of these types are kept
+    pub event_types: Option<HashSet<String>>,
+    /// Events of these types are removed
+    pub exclude_event_types: HashSet<String>,
+}
+
+impl OCELTypeFilter {
+    /// Check if the filter does not remove anything
+    pub fn is_empty(&self) -> bool {
+        self.object_types.is_none()
+            && self.exclude_object_types.is_empty()
+            && self.event_types.is_none()
+            && self.exclude_event_types.is_empty()
+    }
+
+    /// Deterministic string representation of the filter (e.g., for cache keys)
+    pub fn to_key(&self) -> String {
+        let sorted = |types: &HashSet<String>| {
+            let mut types: Vec<_> = types.iter().map(String::as_str).collect();
+            types.sort_unstable();
+            types.join(",")
+        };
+        format!(
+            "{:?}|{}|{:?}|{}",
+            self.object_types.as_ref().map(sorted),
+            sorted(&self.exclude_object_types),
+            self.event_types.as_ref().map(sorted),
+            sorted(&self.exclude_event_types)
+        )
+    }
+
+    pub fn keeps_object_type(&self, object_type: &str) -> bool {
+        self.object_types
+            .as_ref()
+            .is_none_or(|types| types.contains(object_type))
+            && !self.exclude_object_types.contains(object_type)
+    }
+
+    pub fn keeps_event_type(&self, event_type: &str) -> bool {
+        self.event_types
+            .as_ref()
+            .is_none_or(|types| types.contains(event_type))
+            && !self.exclude_event_types.contains(event_type)
+    }
+}
+
+///
+/// Remove all events and objects of an [OCEL] whose types are excluded by the filter
+///
+/// Relationships (E2O and O2O) to removed objects are dropped as well.
+/// Events which were related to objects but lost all their relationships are also removed.
+///
+pub fn filter_ocel_types(ocel: &mut OCEL, filter: &OCELTypeFilter) {
+    if filter.is_empty() {
+        return;
+    }
+    ocel.object_types.retain(|t| filter.keeps_object_type(&t.name));
+    ocel.event_types.retain(|t| filter.keeps_event_type(&t.name));
+    ocel.objects
+        .retain(|o| filter.keeps_object_type(&o.object_type));
+    let object_ids: HashSet<String> = ocel.objects.iter().map(|o| o.id.clone()).collect();
+    retain_relationships(ocel, &object_ids);
+    ocel.events.retain(|e| filter.keeps_event_type(&e.event_type));
+}
+
+///
+/// Remove all E2O and O2O relationships to objects not in `object_ids`
+///
+/// Events which were related to objects but lost all their relationships are removed.
+///
+pub fn retain_relationships(ocel: &mut OCEL, object_ids: &HashSet<String>) {
+    ocel.events.retain_mut(|e| {
+        let had_relationships = !e.relationships.is_empty();
+        e.relationships
+            .retain(|r| object_ids.contains(&r.object_id));
+        !had_relationships || !e.relationships.is_empty()
+    });
+    for o in ocel.objects.iter_mut() {
+        o.relationships
+            .retain(|r| object_ids.contains(&r.object_id));
+    }
+}
+
+/// Policy for selecting the objects kept when slicing an [OCEL] by time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SliceObjectPolicy {
+    /// Objects related to events in the window, including objects related to them via O2O relationships
+    #[default]
+    Related,
+    /// Only objects related to events in the window
+    Touched,
+    /// Only objects whose lifecycle (all related events) lies fully in the window
+    Contained,
+}
+
+impl FromStr for SliceObjectPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "related" => Ok(Self::Related),
+            "touched" => Ok(Self::Touched),
+            "contained" => Ok(Self::Contained),
+            _ => Err(format!(
+                "Invalid object policy '{s}' (expected 'related', 'touched' or 'contained')"
+            )),
+        }
+    }
+}
+
+///
+/// Slice an [OCEL] to the time window from `start` (inclusive) to `end` (exclusive)
+///
+/// Keeps all events in the window and the objects selected by the [SliceObjectPolicy].
+/// E2O/O2O relationships to removed objects are dropped (events which lose all their related objects are removed)
+/// and object attribute changes recorded at or after `end` are removed.
+///
+pub fn slice_ocel(
+    ocel: &OCEL,
+    start: &DateTime<FixedOffset>,
+    end: &DateTime<FixedOffset>,
+    object_policy: SliceObjectPolicy,
+) -> OCEL {
+    let in_window = |t: &DateTime<FixedOffset>| t >= start && t < end;
+    let events: Vec<_> = ocel
+        .events
+        .iter()
+        .filter(|e| in_window(&e.time))
+        .cloned()
+        .collect();
+    let touched: HashSet<&String> = events
+        .iter()
+        .flat_map(|e| e.relationships.iter().map(|r| &r.object_id))
+        .collect();
+    let object_ids: HashSet<String> = match object_policy {
+        SliceObjectPolicy::Touched => touched.into_iter().cloned().collect(),
+        SliceObjectPolicy::Related => ocel
+            .objects
+            .iter()
+            .filter(|o| touched.contains(&o.id))
+            .flat_map(|o| {
+                std::iter::once(&o.id).chain(o.relationships.iter().map(|r| &r.object_id))
+            })
+            .cloned()
+            .collect(),
+        SliceObjectPolicy::Contained => {
+            let outside: HashSet<&String> = ocel
+                .events
+                .iter()
+                .filter(|e| !in_window(&e.time))
+                .flat_map(|e| e.relationships.iter().map(|r| &r.object_id))
+                .collect();
+            touched
+                .into_iter()
+                .filter(|o| !outside.contains(o))
+                .cloned()
+                .collect()
+        }
+    };
+    let objects = ocel
+        .objects
+        .iter()
+        .filter(|o| object_ids.contains(&o.id))
+        .map(|o| {
+            let mut o = o.clone();
+            o.attributes.retain(|a| a.time < *end);
+            o
+        })
+        .collect();
+    let mut sliced = OCEL {
+        event_types: ocel.event_types.clone(),
+        object_types: ocel.object_types.clone(),
+        events,
+        objects,
+    };
+    retain_relationships(&mut sliced, &object_ids);
+    sliced
+}
+
+///
+/// Slice an OCEL to a time window
+///
+/// Returns the sliced OCEL as dict of DataFrames, in the same shape as the input
+/// (the `object_changes` layout and the timezone and time unit of datetime columns are inferred from the input, unless given)
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+/// * `start` - Start of the time window (inclusive; RFC 3339/ISO 8601 string)
+/// * `end` - End of the time window (exclusive; RFC 3339/ISO 8601 string)
+/// * `object_policy` - Optional policy for the kept objects: `"related"` (default), `"touched"` or `"contained"`
+/// * `object_changes_format` - Optional layout of the `object_changes` DataFrame: `"wide"` or `"long"` (default: layout of the input)
+/// * `initial_attributes` - Optional policy for the initial attribute values in the `objects` DataFrame: `"epoch"`, `"earliest"` (default) or `"first_event"`
+/// * `timezone` - Optional timezone handling for datetime columns: `"utc"`, `"naive"` or a named timezone (default: timezone of the input)
+/// * `time_unit` - Optional time unit of datetime columns: `"ns"`, `"us"` or `"ms"` (default: time unit of the input)
+/// * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+///
+#[pyfunction]
+#[pyo3(signature = (ocel, start, end, object_policy=None, object_changes_format=None, initial_attributes=None, timezone=None, time_unit=None, out_of_range=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn slice_ocel_rs(
+    ocel: HashMap<String, PyDataFrame>,
+    start: String,
+    end: String,
+    object_policy: Option<String>,
+    object_changes_format: Option<String>,
+    initial_attributes: Option<String>,
+    timezone: Option<String>,
+    time_unit: Option<String>,
+    out_of_range: Option<String>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let ocel_dfs = ocel_dfs_from_py(ocel)?;
+    let mut options = infer_df_options(&ocel_dfs);
+    if let Some(format) = parse_optional(object_changes_format)? {
+        options.object_changes_format = format;
+    }
+    if let Some(policy) = parse_optional(initial_attributes)? {
+        options.initial_attributes = policy;
+    }
+    if let Some(timezone) = parse_optional(timezone)? {
+        options.timezone = timezone;
+    }
+    if let Some(unit) = parse_optional(time_unit)? {
+        options.timestamps.unit = unit;
+    }
+    if let Some(policy) = parse_optional(out_of_range)? {
+        options.timestamps.out_of_range = policy;
+    }
+    let ocel = ocel_dfs_to_ocel(&ocel_dfs)
+        .map_err(|e| PyValueError::new_err(format!("Failed to convert DataFrames to OCEL: {e:?}")))?;
+    let start = parse_timestamp(&start).map_err(PyValueError::new_err)?;
+    let end = parse_timestamp(&end).map_err(PyValueError::new_err)?;
+    let sliced = slice_ocel(&ocel, &start, &end, parse_option(object_policy)?);
+    let sliced_dfs = ocel2_to_df_with(&sliced, &options)
+        .map_err(|e| PyValueError::new_err(format!("Failed to convert OCEL to DataFrames: {e}")))?;
+    Ok(ocel_dfs_to_py(sliced_dfs))
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use process_mining::{
+    convert_log_to_dataframe,
+    event_log::{Attribute, AttributeValue, Attributes, Event, EventLog, Trace},
+    export_xes_event_log_to_file_path,
+    ocel::ocel_struct::OCELAttributeValue,
+    OCEL,
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    pyfunction, PyResult,
+};
+use pyo3_polars::PyDataFrame;
+
+use super::{
+    initial_object_attribute_values, ocel_from_py, InitialAttributePolicy, OCEL_EVENT_ID_KEY,
+};
+
+/// Convert an [OCELAttributeValue] to the corresponding XES [AttributeValue]
+pub fn ocel_attribute_val_to_xes(val: &OCELAttributeValue) -> AttributeValue {
+    match val {
+        OCELAttributeValue::String(s) => AttributeValue::String(s.clone()),
+        OCELAttributeValue::Time(t) => AttributeValue::Date(*t),
+        OCELAttributeValue::Integer(i) => AttributeValue::Int(*i),
+        OCELAttributeValue::Float(f) => AttributeValue::Float(*f),
+        OCELAttributeValue::Boolean(b) => AttributeValue::Boolean(*b),
+        OCELAttributeValue::Null => AttributeValue::None(),
+    }
+}
+
+///
+/// Flatten an [OCEL] on an object type, resulting in a case-centric [EventLog]
+///
+/// Every object of the given type becomes a trace (with the object id as `concept:name` and the initial object attribute values as trace attributes),
+/// containing all events related to the object (sorted by time).
+/// Events keep their attributes and additionally have `concept:name` (event type), `time:timestamp` and `ocel:eid` attributes.
+///
+pub fn flatten_ocel(ocel: &OCEL, object_type: &str) -> EventLog {
+    let mut events_per_object: HashMap<&str, Vec<usize>> = ocel
+        .objects
+        .iter()
+        .filter(|o| o.object_type == object_type)
+        .map(|o| (o.id.as_str(), Vec::new()))
+        .collect();
+    for (i, e) in ocel.events.iter().enumerate() {
+        for r in &e.relationships {
+            if let Some(evs) = events_per_object.get_mut(r.object_id.as_str()) {
+                // Avoid duplicates if an event is related to the same object with multiple qualifiers
+                if evs.last() != Some(&i) {
+                    evs.push(i);
+                }
+            }
+        }
+    }
+    let initial_values =
+        initial_object_attribute_values(ocel, InitialAttributePolicy::EarliestValue);
+    let traces = ocel
+        .objects
+        .iter()
+        .zip(initial_values)
+        .filter(|(o, _)| o.object_type == object_type)
+        .map(|(o, values)| {
+            let mut attributes: Attributes = vec![Attribute::new(
+                "concept:name".to_string(),
+                AttributeValue::String(o.id.clone()),
+            )];
+            let mut values: Vec<_> = values.into_iter().collect();
+            values.sort_by_key(|(name, _)| *name);
+            attributes.extend(
+                values
+                    .into_iter()
+                    .filter(|(name, _)| *name != "concept:name")
+                    .map(|(name, value)| {
+                        Attribute::new(name.to_string(), ocel_attribute_val_to_xes(value))
+                    }),
+            );
+            let mut event_indices = events_per_object.remove(o.id.as_str()).unwrap_or_default();
+            event_indices.sort_by_key(|i| ocel.events[*i].time);
+            let events = event_indices
+                .into_iter()
+                .map(|i| {
+                    let e = &ocel.events[i];
+                    let mut attributes: Attributes = vec![
+                        Attribute::new(
+                            "concept:name".to_string(),
+                            AttributeValue::String(e.event_type.clone()),
+                        ),
+                        Attribute::new("time:timestamp".to_string(), AttributeValue::Date(e.time)),
+                        Attribute::new(
+                            OCEL_EVENT_ID_KEY.to_string(),
+                            AttributeValue::String(e.id.clone()),
+                        ),
+                    ];
+                    attributes.extend(
+                        e.attributes
+                            .iter()
+                            .filter(|a| a.name != "concept:name" && a.name != "time:timestamp")
+                            .map(|a| {
+                                Attribute::new(a.name.clone(), ocel_attribute_val_to_xes(&a.value))
+                            }),
+                    );
+                    Event { attributes }
+                })
+                .collect();
+            Trace { attributes, events }
+        })
+        .collect();
+    EventLog {
+        attributes: Vec::new(),
+        traces,
+        extensions: None,
+        classifiers: None,
+        global_trace_attrs: None,
+        global_event_attrs: None,
+    }
+}
+
+///
+/// Flatten an OCEL on an object type
+///
+/// Returns a Polars [DataFrame] with the same schema as `import_xes_rs` (`case:concept:name` is the object id)
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+/// * `object_type` - The object type to flatten on
+/// * `path` - Optional filepath of a .xes or .xes.gz file the flattened log should be exported to
+///
+#[pyfunction]
+#[pyo3(signature = (ocel, object_type, path=None))]
+pub fn flatten_ocel_rs(
+    ocel: HashMap<String, PyDataFrame>,
+    object_type: String,
+    path: Option<String>,
+) -> PyResult<PyDataFrame> {
+    let ocel = ocel_from_py(ocel)?;
+    if !ocel.objects.iter().any(|o| o.object_type == object_type) {
+        return Err(PyValueError::new_err(format!(
+            "No objects of type '{object_type}' in OCEL"
+        )));
+    }
+    let log = flatten_ocel(&ocel, &object_type);
+    if let Some(path) = path {
+        export_xes_event_log_to_file_path(&log, path)
+            .map_err(|e| PyTypeError::new_err(format!("Failed to export XES: {e:?}")))?;
+    }
+    let df = convert_log_to_dataframe(&log, false)
+        .map_err(|e| PyTypeError::new_err(format!("Failed to convert log to dataframe: {e:?}")))?;
+    Ok(PyDataFrame(df))
+}
@@ -0,0 +1,194 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::DateTime;
+use process_mining::{
+    convert_dataframe_to_log,
+    event_log::{Attribute, AttributeValue, EventLog},
+    ocel::ocel_struct::{
+        OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
+        OCELRelationship,
+    },
+    OCEL,
+};
+use polars::prelude::*;
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    pyfunction, PyResult,
+};
+use pyo3_polars::PyDataFrame;
+
+use super::{df_to_ocel::types_from_attributes, ocel2_to_df, ocel_dfs_to_py};
+
+/// Convert an XES [AttributeValue] to the corresponding [OCELAttributeValue]
+///
+/// Lists and containers have no OCEL counterpart and are converted to null
+pub fn xes_attribute_val_to_ocel(val: &AttributeValue) -> OCELAttributeValue {
+    match val {
+        AttributeValue::String(s) => OCELAttributeValue::String(s.clone()),
+        AttributeValue::Date(d) => OCELAttributeValue::Time(*d),
+        AttributeValue::Int(i) => OCELAttributeValue::Integer(*i),
+        AttributeValue::Float(f) => OCELAttributeValue::Float(*f),
+        AttributeValue::Boolean(b) => OCELAttributeValue::Boolean(*b),
+        AttributeValue::ID(id) => OCELAttributeValue::String(id.to_string()),
+        _ => OCELAttributeValue::Null,
+    }
+}
+
+/// String representation of an XES [AttributeValue] used in object ids
+fn xes_attribute_val_to_id(val: &AttributeValue) -> Option<String> {
+    match val {
+        AttributeValue::String(s) => Some(s.clone()),
+        AttributeValue::Int(i) => Some(i.to_string()),
+        AttributeValue::Float(f) => Some(f.to_string()),
+        AttributeValue::Boolean(b) => Some(b.to_string()),
+        AttributeValue::ID(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+fn find_attribute<'a>(attributes: &'a [Attribute], key: &str) -> Option<&'a AttributeValue> {
+    attributes.iter().find(|a| a.key == key).map(|a| &a.value)
+}
+
+///
+/// Convert a case-centric [EventLog] to an [OCEL]
+///
+/// Every trace becomes an object of type `case_object_type` (with the trace attributes as initial object attributes).
+/// The values of the event attributes `object_columns` become objects of the type named after the attribute,
+/// related to the events with the attribute name as qualifier.
+/// All other event attributes are kept as event attributes.
+///
+/// Object ids are namespaced by their type (`<type>:<value>`, e.g., `case:1` or `org:resource:Mike`),
+/// so that equal values of different columns do not collide.
+/// Returns an error if an event has no `time:timestamp` date attribute.
+///
+pub fn xes_to_ocel(
+    log: &EventLog,
+    case_object_type: &str,
+    object_columns: &[String],
+) -> PolarsResult<OCEL> {
+    let object_columns: HashSet<&str> = object_columns.iter().map(|c| c.as_str()).collect();
+    let mut objects: Vec<OCELObject> = Vec::new();
+    let mut seen_objects: HashSet<(String, String)> = HashSet::new();
+    let mut events: Vec<OCELEvent> = Vec::new();
+    for (trace_index, trace) in log.traces.iter().enumerate() {
+        let case_name = find_attribute(&trace.attributes, "concept:name")
+            .and_then(xes_attribute_val_to_id)
+            .unwrap_or_else(|| format!("case_{trace_index}"));
+        let case_id = format!("{case_object_type}:{case_name}");
+        objects.push(OCELObject {
+            id: case_id.clone(),
+            object_type: case_object_type.to_string(),
+            attributes: trace
+                .attributes
+                .iter()
+                .filter(|a| a.key != "concept:name")
+                .map(|a| OCELObjectAttribute {
+                    name: a.key.clone(),
+                    value: xes_attribute_val_to_ocel(&a.value),
+                    time: DateTime::UNIX_EPOCH.fixed_offset(),
+                })
+                .collect(),
+            relationships: Vec::new(),
+        });
+        for (event_index, event) in trace.events.iter().enumerate() {
+            let mut relationships = vec![OCELRelationship {
+                object_id: case_id.clone(),
+                qualifier: case_object_type.to_string(),
+            }];
+            let mut attributes = Vec::new();
+            for a in &event.attributes {
+                if a.key == "concept:name" || a.key == "time:timestamp" {
+                    continue;
+                }
+                if object_columns.contains(a.key.as_str()) {
+                    if let Some(value) = xes_attribute_val_to_id(&a.value) {
+                        let object_id = format!("{}:{value}", a.key);
+                        if seen_objects.insert((a.key.clone(), object_id.clone())) {
+                            objects.push(OCELObject {
+                                id: object_id.clone(),
+                                object_type: a.key.clone(),
+                                attributes: Vec::new(),
+                                relationships: Vec::new(),
+                            });
+                        }
+                        relationships.push(OCELRelationship {
+                            object_id,
+                            qualifier: a.key.clone(),
+                        });
+                    }
+                } else {
+                    attributes.push(OCELEventAttribute {
+                        name: a.key.clone(),
+                        value: xes_attribute_val_to_ocel(&a.value),
+                    });
+                }
+            }
+            let time = match find_attribute(&event.attributes, "time:timestamp") {
+                Some(AttributeValue::Date(d)) => *d,
+                _ => polars_bail!(
+                    ComputeError: "Event {} of case '{}' has no 'time:timestamp' date attribute",
+                    event_index,
+                    case_name
+                ),
+            };
+            events.push(OCELEvent {
+                id: format!("{case_name}_{event_index}"),
+                event_type: find_attribute(&event.attributes, "concept:name")
+                    .and_then(xes_attribute_val_to_id)
+                    .unwrap_or_default(),
+                time,
+                attributes,
+                relationships,
+            });
+        }
+    }
+    let event_types = types_from_attributes(
+        events.iter().map(|e| &e.event_type),
+        events
+            .iter()
+            .flat_map(|e| e.attributes.iter().map(move |a| (&e.event_type, &a.name, &a.value))),
+    );
+    let object_types = types_from_attributes(
+        objects.iter().map(|o| &o.object_type),
+        objects
+            .iter()
+            .flat_map(|o| o.attributes.iter().map(move |a| (&o.object_type, &a.name, &a.value))),
+    );
+    Ok(OCEL {
+        event_types,
+        object_types,
+        events,
+        objects,
+    })
+}
+
+///
+/// Convert a case-centric event log DataFrame (e.g., as returned by `import_xes_rs`) to an OCEL
+///
+/// Returns the OCEL as dict of DataFrames (see `import_ocel_xml_rs`); object ids are prefixed with their type (`<type>:<value>`)
+///
+/// * `df` - The Polars DataFrame representing the event log
+/// * `object_columns` - Event attribute columns whose values become objects (of the type named after the column)
+/// * `case_object_type` - Optional object type of the case objects (default: `case`)
+///
+#[pyfunction]
+#[pyo3(signature = (df, object_columns, case_object_type=None))]
+pub fn xes_to_ocel_rs(
+    df: PyDataFrame,
+    object_columns: Vec<String>,
+    case_object_type: Option<String>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let df: polars::frame::DataFrame = df.into();
+    let log = convert_dataframe_to_log(&df)
+        .map_err(|e| PyTypeError::new_err(format!("Failed to convert dataframe to log: {e:?}")))?;
+    let ocel = xes_to_ocel(
+        &log,
+        case_object_type.as_deref().unwrap_or("case"),
+        &object_columns,
+    )
+    .map_err(|e| PyValueError::new_err(format!("Failed to convert event log to OCEL: {e}")))?;
+    let ocel_dfs = ocel2_to_df(&ocel)
+        .map_err(|e| PyValueError::new_err(format!("Failed to convert OCEL to DataFrames: {e:?}")))?;
+    Ok(ocel_dfs_to_py(ocel_dfs))
+}
@@ -1,28 +1,54 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
-use chrono::DateTime;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
 use polars::{prelude::*, series::Series};
 use process_mining::{
-    import_ocel_json_from_path,
+    export_ocel_json_path, export_ocel_xml_path, import_ocel_json_from_path,
     ocel::{
         ocel_struct::OCELAttributeValue,
         xml_ocel_import::{import_ocel_xml_file_with, OCELImportOptions},
     },
     OCEL,
 };
-use pyo3::{pyfunction, PyResult};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    pyfunction, PyResult,
+};
 use pyo3_polars::PyDataFrame;
 
+pub use df_to_ocel::ocel_dfs_to_ocel;
+
+mod df_to_ocel;
+pub mod filter;
+pub mod flatten;
+pub mod from_xes;
+pub mod ocdfg;
+pub mod ocel1;
+pub mod parquet;
+pub mod snapshot;
+pub mod summary;
+pub mod validate;
+
+use crate::{
+    cache::{cache_key, load_cached_df, store_cached_df},
+    parse_option, warn_py,
+    time::{apply_timezone, OutOfRangePolicy, TimestampOptions, TimestampUnit, TimezoneOption},
+};
+use filter::{filter_ocel_types, OCELTypeFilter};
+use ocel1::{import_ocel1_json_from_path, import_ocel1_xml_from_path, is_ocel1_xml_file};
+use snapshot::ObjectAttributeHistory;
+use validate::ensure_valid_ocel;
+
 fn ocel_attribute_val_to_any_value<'a>(
     val: &'a OCELAttributeValue,
+    timestamps: &TimestampOptions,
 ) -> AnyValue<'a> {
     match val {
         OCELAttributeValue::String(s) => AnyValue::StringOwned(s.into()),
-        OCELAttributeValue::Time(t) => AnyValue::Datetime(
-            t.timestamp_nanos_opt().unwrap(),
-            TimeUnit::Nanoseconds,
-            None,
-        ),
+        OCELAttributeValue::Time(t) => timestamps.to_any_value(t),
         OCELAttributeValue::Integer(i) => AnyValue::Int64(*i),
         OCELAttributeValue::Float(f) => AnyValue::Float64(*f),
         OCELAttributeValue::Boolean(b) => AnyValue::Boolean(*b),
@@ -37,6 +63,85 @@ pub const OCEL_OBJECT_ID_2_KEY: &str = "ocel:oid_2";
 pub const OCEL_OBJECT_TYPE_KEY: &str = "ocel:type";
 pub const OCEL_QUALIFIER_KEY: &str = "ocel:qualifier";
 pub const OCEL_CHANGED_FIELD_KEY: &str = "ocel:field";
+pub const OCEL_VALUE_STRING_KEY: &str = "ocel:value:string";
+pub const OCEL_VALUE_TIME_KEY: &str = "ocel:value:time";
+pub const OCEL_VALUE_INTEGER_KEY: &str = "ocel:value:integer";
+pub const OCEL_VALUE_FLOAT_KEY: &str = "ocel:value:float";
+pub const OCEL_VALUE_BOOLEAN_KEY: &str = "ocel:value:boolean";
+/// Typed value columns of the long `object_changes` format
+pub const OCEL_VALUE_COLUMNS: [&str; 5] = [
+    OCEL_VALUE_STRING_KEY,
+    OCEL_VALUE_TIME_KEY,
+    OCEL_VALUE_INTEGER_KEY,
+    OCEL_VALUE_FLOAT_KEY,
+    OCEL_VALUE_BOOLEAN_KEY,
+];
+
+/// Layout of the `object_changes` [DataFrame]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ObjectChangesFormat {
+    /// One column per object attribute (of all object types), with a single non-null value per row
+    #[default]
+    Wide,
+    /// Tidy format with one row per attribute value and typed value columns (see [`OCEL_VALUE_COLUMNS`])
+    Long,
+}
+
+impl FromStr for ObjectChangesFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wide" => Ok(Self::Wide),
+            "long" => Ok(Self::Long),
+            _ => Err(format!(
+                "Invalid object changes format '{s}' (expected 'wide' or 'long')"
+            )),
+        }
+    }
+}
+
+/// Policy for determining the initial attribute values of objects (i.e., the values in the `objects` [DataFrame])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InitialAttributePolicy {
+    /// Only values recorded at the Unix epoch (`1970-01-01T00:00:00Z`) are initial values
+    EpochOnly,
+    /// The earliest recorded value of each attribute is the initial value
+    #[default]
+    EarliestValue,
+    /// The value of each attribute at the time of the first event related to the object
+    ///
+    /// Objects without related events use the earliest recorded values
+    ValueAtFirstEvent,
+}
+
+impl FromStr for InitialAttributePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "epoch" => Ok(Self::EpochOnly),
+            "earliest" => Ok(Self::EarliestValue),
+            "first_event" => Ok(Self::ValueAtFirstEvent),
+            _ => Err(format!(
+                "Invalid initial attribute policy '{s}' (expected 'epoch', 'earliest' or 'first_event')"
+            )),
+        }
+    }
+}
+
+/// Options for converting an [`OCEL`] to [`OCEL2DataFrames`]
+#[derive(Debug, Clone, Default)]
+pub struct OCEL2DataFrameOptions {
+    /// Layout of the `object_changes` [DataFrame]
+    pub object_changes_format: ObjectChangesFormat,
+    /// Policy for determining the initial attribute values in the `objects` [DataFrame]
+    pub initial_attributes: InitialAttributePolicy,
+    /// Timezone handling for datetime columns (timezone-aware UTC by default, as for event log imports)
+    pub timezone: TimezoneOption,
+    /// Time unit and handling of out-of-range values for datetime columns
+    pub timestamps: TimestampOptions,
+}
 
 pub struct OCEL2DataFrames {
     pub objects: DataFrame,
@@ -45,7 +150,20 @@ pub struct OCEL2DataFrames {
     pub o2o: DataFrame,
     pub e2o: DataFrame,
 }
-pub fn ocel2_to_df(ocel: &OCEL) -> OCEL2DataFrames {
+pub fn ocel2_to_df(ocel: &OCEL) -> PolarsResult<OCEL2DataFrames> {
+    ocel2_to_df_with(ocel, &OCEL2DataFrameOptions::default())
+}
+
+///
+/// Convert an [OCEL] to [OCEL2DataFrames] with the given options
+///
+/// Fails if the timezone is invalid or, for [OutOfRangePolicy::Error], if a timestamp is out of range
+///
+pub fn ocel2_to_df_with(
+    ocel: &OCEL,
+    options: &OCEL2DataFrameOptions,
+) -> PolarsResult<OCEL2DataFrames> {
+    ensure_timestamps_in_range(ocel, &options.timestamps)?;
     let object_attributes: HashSet<String> = ocel
         .object_types
         .iter()
@@ -63,35 +181,26 @@ pub fn ocel2_to_df(ocel: &OCEL) -> OCEL2DataFrames {
             "Warning: Global object attributes is not a superset of actual object attributes"
         );
     }
+    let initial_values = initial_object_attribute_values(ocel, options.initial_attributes);
     let object_attributes_initial: HashSet<String> = object_attributes
         .clone()
         .into_iter()
-        .filter(|a| {
-            ocel.objects.iter().any(|o| {
-                o.attributes
-                    .iter()
-                    .any(|oa| &oa.name == a && oa.time == DateTime::UNIX_EPOCH)
-            })
-        })
+        .filter(|a| initial_values.iter().any(|vals| vals.contains_key(a.as_str())))
         .collect();
-    let objects_df = DataFrame::from_iter(
+    let mut objects_df = DataFrame::from_iter(
         object_attributes_initial
             .into_iter()
             .map(|name| {
                 Series::from_any_values(
                     (&name).into(),
-                    ocel.objects
+                    initial_values
                         .iter()
-                        .map(|o| {
-                            let attr = o
-                                .attributes
-                                .iter()
-                                .find(|a| a.name == name && a.time == DateTime::UNIX_EPOCH);
-                            let val = match attr {
-                                Some(v) => &v.value,
+                        .map(|vals| {
+                            let val = match vals.get(name.as_str()) {
+                                Some(v) => *v,
                                 None => &OCELAttributeValue::Null,
                             };
-                            ocel_attribute_val_to_any_value(val)
+                            ocel_attribute_val_to_any_value(val, &options.timestamps)
                         })
                         .collect::<Vec<_>>()
                         .as_ref(),
@@ -162,13 +271,7 @@ pub fn ocel2_to_df(ocel: &OCEL) -> OCEL2DataFrames {
             OCEL_EVENT_TIMESTAMP_KEY.into(),
             &all_evs_with_rels
                 .iter()
-                .map(|(e, _r)| {
-                    AnyValue::Datetime(
-                        e.time.timestamp_nanos_opt().unwrap(),
-                        TimeUnit::Nanoseconds,
-                        None,
-                    )
-                })
+                .map(|(e, _r)| options.timestamps.to_any_value(&e.time))
                 .collect::<Vec<_>>(),
             false,
         )
@@ -252,7 +355,148 @@ pub fn ocel2_to_df(ocel: &OCEL) -> OCEL2DataFrames {
         .unwrap(),
     ]);
 
-    let mut object_changes_df = DataFrame::from_iter(
+    let mut object_changes_df = match options.object_changes_format {
+        ObjectChangesFormat::Wide => {
+            object_changes_wide_df(ocel, object_attributes, &options.timestamps)
+        }
+        ObjectChangesFormat::Long => object_changes_long_df(ocel, &options.timestamps),
+    };
+    let event_attributes: HashSet<String> = ocel
+        .event_types
+        .iter()
+        .flat_map(|et| &et.attributes)
+        .map(|at| at.name.clone())
+        .collect();
+    let mut events_df = DataFrame::from_iter(
+        event_attributes
+            .into_iter()
+            .map(|name| {
+                Series::from_any_values(
+                    (&name).into(),
+                    ocel.events
+                        .iter()
+                        .map(|e| {
+                            let attr = e.attributes.iter().find(|a| a.name == name);
+                            let val = match attr {
+                                Some(v) => &v.value,
+                                None => &OCELAttributeValue::Null,
+                            };
+                            ocel_attribute_val_to_any_value(val, &options.timestamps)
+                        })
+                        .collect::<Vec<_>>()
+                        .as_ref(),
+                    false,
+                )
+                .unwrap()
+            })
+            .chain(vec![
+                Series::from_any_values(
+                    OCEL_EVENT_ID_KEY.into(),
+                    &ocel
+                        .events
+                        .iter()
+                        .map(|o| AnyValue::StringOwned(o.id.clone().into()))
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .unwrap(),
+                Series::from_any_values(
+                    OCEL_EVENT_TYPE_KEY.into(),
+                    &ocel
+                        .events
+                        .iter()
+                        .map(|o| AnyValue::StringOwned(o.event_type.clone().into()))
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .unwrap(),
+                Series::from_any_values(
+                    OCEL_EVENT_TIMESTAMP_KEY.into(),
+                    &ocel
+                        .events
+                        .iter()
+                        .map(|o| options.timestamps.to_any_value(&o.time))
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .unwrap(),
+            ]),
+    );
+    events_df
+        .sort_in_place(vec![OCEL_EVENT_TIMESTAMP_KEY], SortMultipleOptions::default().with_maintain_order(true))?;
+
+    e2o_df
+        .sort_in_place(vec![OCEL_EVENT_TIMESTAMP_KEY], SortMultipleOptions::default().with_maintain_order(true))?;
+
+    object_changes_df
+        .sort_in_place(vec![OCEL_EVENT_TIMESTAMP_KEY], SortMultipleOptions::default().with_maintain_order(true))?;
+    for df in [
+        &mut objects_df,
+        &mut events_df,
+        &mut object_changes_df,
+        &mut e2o_df,
+    ] {
+        apply_timezone(df, &options.timezone)?;
+    }
+    Ok(OCEL2DataFrames {
+        objects: objects_df,
+        events: events_df,
+        object_changes: object_changes_df,
+        o2o: o2o_df,
+        e2o: e2o_df,
+    })
+}
+
+/// Initial attribute values of each object (in the order of `ocel.objects`) according to the given policy
+fn initial_object_attribute_values(
+    ocel: &OCEL,
+    policy: InitialAttributePolicy,
+) -> Vec<HashMap<&str, &OCELAttributeValue>> {
+    let mut first_event_times: HashMap<&str, DateTime<FixedOffset>> = HashMap::new();
+    if policy == InitialAttributePolicy::ValueAtFirstEvent {
+        for e in &ocel.events {
+            for r in &e.relationships {
+                first_event_times
+                    .entry(r.object_id.as_str())
+                    .and_modify(|t| *t = (*t).min(e.time))
+                    .or_insert(e.time);
+            }
+        }
+    }
+    ocel.objects
+        .iter()
+        .map(|o| match policy {
+            InitialAttributePolicy::EpochOnly => {
+                let mut vals = HashMap::new();
+                for oa in o
+                    .attributes
+                    .iter()
+                    .filter(|oa| oa.time == DateTime::UNIX_EPOCH)
+                {
+                    vals.entry(oa.name.as_str()).or_insert(&oa.value);
+                }
+                vals
+            }
+            InitialAttributePolicy::EarliestValue => {
+                ObjectAttributeHistory::new(o).earliest_values()
+            }
+            InitialAttributePolicy::ValueAtFirstEvent => {
+                let history = ObjectAttributeHistory::new(o);
+                match first_event_times.get(o.id.as_str()) {
+                    Some(t) => history.values_at(t),
+                    None => history.earliest_values(),
+                }
+            }
+        })
+        .collect()
+}
+
+fn object_changes_wide_df(
+    ocel: &OCEL,
+    object_attributes: HashSet<String>,
+    timestamps: &TimestampOptions,
+) -> DataFrame {
+    DataFrame::from_iter(
         object_attributes
             .into_iter()
             .map(|name| {
@@ -266,7 +510,7 @@ pub fn ocel2_to_df(ocel: &OCEL) -> OCEL2DataFrames {
                         })
                         .map(|a| {
                             if a.name == name {
-                                ocel_attribute_val_to_any_value(&a.value)
+                                ocel_attribute_val_to_any_value(&a.value, timestamps)
                             } else {
                                 AnyValue::Null
                             }
@@ -327,106 +571,166 @@ pub fn ocel2_to_df(ocel: &OCEL) -> OCEL2DataFrames {
                                 // .filter(|oa| oa.time != DateTime::UNIX_EPOCH)
                                 .map(|oa| oa.time)
                         })
-                        .map(|date| {
-                            AnyValue::Datetime(
-                                date.timestamp_nanos_opt().unwrap(),
-                                TimeUnit::Nanoseconds,
-                                None,
-                            )
-                        })
+                        .map(|date| timestamps.to_any_value(&date))
                         .collect::<Vec<_>>(),
                     false,
                 )
                 .unwrap(),
             ]),
-    );
-    let event_attributes: HashSet<String> = ocel
-        .event_types
+    )
+}
+
+/// Build the `object_changes` [DataFrame] in long format
+///
+/// Each row holds exactly one attribute value, stored in the value column matching its type
+/// (see [`OCEL_VALUE_COLUMNS`]); all other value columns are null.
+fn object_changes_long_df(ocel: &OCEL, timestamps: &TimestampOptions) -> DataFrame {
+    let all_obj_attrs: Vec<_> = ocel
+        .objects
         .iter()
-        .flat_map(|et| &et.attributes)
-        .map(|at| at.name.clone())
+        .flat_map(|o| o.attributes.iter().map(move |oa| (o, oa)))
         .collect();
-    let mut events_df = DataFrame::from_iter(
-        event_attributes
-            .into_iter()
-            .map(|name| {
-                Series::from_any_values(
-                    (&name).into(),
-                    ocel.events
-                        .iter()
-                        .map(|e| {
-                            let attr = e.attributes.iter().find(|a| a.name == name);
-                            let val = match attr {
-                                Some(v) => &v.value,
-                                None => &OCELAttributeValue::Null,
-                            };
-                            ocel_attribute_val_to_any_value(val)
-                        })
-                        .collect::<Vec<_>>()
-                        .as_ref(),
-                    false,
-                )
-                .unwrap()
-            })
-            .chain(vec![
-                Series::from_any_values(
-                    OCEL_EVENT_ID_KEY.into(),
-                    &ocel
-                        .events
-                        .iter()
-                        .map(|o| AnyValue::StringOwned(o.id.clone().into()))
-                        .collect::<Vec<_>>(),
-                    false,
-                )
-                .unwrap(),
-                Series::from_any_values(
-                    OCEL_EVENT_TYPE_KEY.into(),
-                    &ocel
-                        .events
-                        .iter()
-                        .map(|o| AnyValue::StringOwned(o.event_type.clone().into()))
-                        .collect::<Vec<_>>(),
-                    false,
-                )
-                .unwrap(),
-                Series::from_any_values(
-                    OCEL_EVENT_TIMESTAMP_KEY.into(),
-                    &ocel
-                        .events
-                        .iter()
-                        .map(|o| {
-                            AnyValue::Datetime(
-                                o.time.timestamp_nanos_opt().unwrap(),
-                                TimeUnit::Nanoseconds,
-                                None,
-                            )
-                        })
-                        .collect::<Vec<_>>(),
-                    false,
-                )
-                .unwrap(),
-            ]),
-    );
-    events_df
-        .sort_in_place(vec![OCEL_EVENT_TIMESTAMP_KEY], SortMultipleOptions::default().with_maintain_order(true))
-        .unwrap();
+    let value_column = |key: &str| {
+        Series::from_any_values_and_dtype(
+            key.into(),
+            &all_obj_attrs
+                .iter()
+                .map(|(_o, oa)| {
+                    if value_column_key(&oa.value) == Some(key) {
+                        ocel_attribute_val_to_any_value(&oa.value, timestamps)
+                    } else {
+                        AnyValue::Null
+                    }
+                })
+                .collect::<Vec<_>>(),
+            &value_column_dtype(key, timestamps),
+            false,
+        )
+        .unwrap()
+    };
+    let mut columns = vec![
+        Series::from_any_values(
+            OCEL_OBJECT_ID_KEY.into(),
+            &all_obj_attrs
+                .iter()
+                .map(|(o, _oa)| AnyValue::StringOwned(o.id.clone().into()))
+                .collect::<Vec<_>>(),
+            false,
+        )
+        .unwrap(),
+        Series::from_any_values(
+            OCEL_OBJECT_TYPE_KEY.into(),
+            &all_obj_attrs
+                .iter()
+                .map(|(o, _oa)| AnyValue::StringOwned(o.object_type.clone().into()))
+                .collect::<Vec<_>>(),
+            false,
+        )
+        .unwrap(),
+        Series::from_any_values(
+            OCEL_CHANGED_FIELD_KEY.into(),
+            &all_obj_attrs
+                .iter()
+                .map(|(_o, oa)| AnyValue::StringOwned(oa.name.clone().into()))
+                .collect::<Vec<_>>(),
+            false,
+        )
+        .unwrap(),
+        Series::from_any_values(
+            OCEL_EVENT_TIMESTAMP_KEY.into(),
+            &all_obj_attrs
+                .iter()
+                .map(|(_o, oa)| timestamps.to_any_value(&oa.time))
+                .collect::<Vec<_>>(),
+            false,
+        )
+        .unwrap(),
+    ];
+    columns.extend(OCEL_VALUE_COLUMNS.iter().map(|key| value_column(key)));
+    DataFrame::from_iter(columns)
+}
 
-    e2o_df
-        .sort_in_place(vec![OCEL_EVENT_TIMESTAMP_KEY], SortMultipleOptions::default().with_maintain_order(true))
-        .unwrap();
+/// Name of the long-format value column in which an attribute value is stored
+fn value_column_key(val: &OCELAttributeValue) -> Option<&'static str> {
+    match val {
+        OCELAttributeValue::String(_) => Some(OCEL_VALUE_STRING_KEY),
+        OCELAttributeValue::Time(_) => Some(OCEL_VALUE_TIME_KEY),
+        OCELAttributeValue::Integer(_) => Some(OCEL_VALUE_INTEGER_KEY),
+        OCELAttributeValue::Float(_) => Some(OCEL_VALUE_FLOAT_KEY),
+        OCELAttributeValue::Boolean(_) => Some(OCEL_VALUE_BOOLEAN_KEY),
+        OCELAttributeValue::Null => None,
+    }
+}
 
-    object_changes_df
-        .sort_in_place(vec![OCEL_EVENT_TIMESTAMP_KEY], SortMultipleOptions::default().with_maintain_order(true))
-        .unwrap();
-    OCEL2DataFrames {
-        objects: objects_df,
-        events: events_df,
-        object_changes: object_changes_df,
-        o2o: o2o_df,
-        e2o: e2o_df,
+/// Data type of a long-format value column (also for value columns without any non-null values)
+fn value_column_dtype(key: &str, timestamps: &TimestampOptions) -> DataType {
+    match key {
+        OCEL_VALUE_TIME_KEY => DataType::Datetime(timestamps.unit.to_polars(), None),
+        OCEL_VALUE_INTEGER_KEY => DataType::Int64,
+        OCEL_VALUE_FLOAT_KEY => DataType::Float64,
+        OCEL_VALUE_BOOLEAN_KEY => DataType::Boolean,
+        _ => DataType::String,
+    }
+}
+
+///
+/// Check that all timestamps of an [OCEL] are representable with the given [TimestampOptions]
+///
+/// Returns an error naming the first offending event/object
+///
+pub fn check_timestamps_in_range(ocel: &OCEL, timestamps: &TimestampOptions) -> Result<(), String> {
+    for e in &ocel.events {
+        if !timestamps.is_in_range(&e.time) {
+            return Err(format!(
+                "Event '{}' has out-of-range timestamp {}",
+                e.id, e.time
+            ));
+        }
+        for a in &e.attributes {
+            if let OCELAttributeValue::Time(t) = &a.value {
+                if !timestamps.is_in_range(t) {
+                    return Err(format!(
+                        "Event '{}' has out-of-range value {} for attribute '{}'",
+                        e.id, t, a.name
+                    ));
+                }
+            }
+        }
+    }
+    for o in &ocel.objects {
+        for a in &o.attributes {
+            if !timestamps.is_in_range(&a.time) {
+                return Err(format!(
+                    "Object '{}' has out-of-range timestamp {} for attribute '{}'",
+                    o.id, a.time, a.name
+                ));
+            }
+            if let OCELAttributeValue::Time(t) = &a.value {
+                if !timestamps.is_in_range(t) {
+                    return Err(format!(
+                        "Object '{}' has out-of-range value {} for attribute '{}'",
+                        o.id, t, a.name
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+///
+/// Fail (for [OutOfRangePolicy::Error]) if an [OCEL] has timestamps that are not representable with the given [TimestampOptions]
+///
+pub fn ensure_timestamps_in_range(ocel: &OCEL, timestamps: &TimestampOptions) -> PolarsResult<()> {
+    if timestamps.out_of_range == OutOfRangePolicy::Error {
+        check_timestamps_in_range(ocel, timestamps).map_err(|e| polars_err!(ComputeError: "{}", e))?;
     }
+    Ok(())
 }
 
+/// Keys of the dict of DataFrames representing an OCEL in Python
+pub const OCEL_DATAFRAME_NAMES: [&str; 5] = ["events", "objects", "o2o", "relations", "object_changes"];
+
 pub fn ocel_dfs_to_py(ocel_dfs: OCEL2DataFrames) -> HashMap<String, PyDataFrame> {
     let mut res: HashMap<String, PyDataFrame> = HashMap::with_capacity(5);
     res.insert("events".to_string(), PyDataFrame(ocel_dfs.events));
@@ -440,22 +744,345 @@ pub fn ocel_dfs_to_py(ocel_dfs: OCEL2DataFrames) -> HashMap<String, PyDataFrame>
     res
 }
 
-#[pyfunction]
-pub fn import_ocel_xml_rs(path: String) -> PyResult<HashMap<String, PyDataFrame>> {
-    let ocel = import_ocel_xml_file_with(
-        &path,
+///
+/// Infer the [OCEL2DataFrameOptions] that produced the given [OCEL2DataFrames]
+///
+/// The `object_changes` layout as well as the timezone and time unit of the event timestamps are inferred;
+/// the initial attribute and out-of-range policies cannot be inferred and use their defaults.
+///
+pub fn infer_df_options(ocel_dfs: &OCEL2DataFrames) -> OCEL2DataFrameOptions {
+    let mut options = OCEL2DataFrameOptions::default();
+    let columns = ocel_dfs.object_changes.get_column_names();
+    if OCEL_VALUE_COLUMNS
+        .iter()
+        .all(|c| columns.iter().any(|n| n.as_str() == *c))
+    {
+        options.object_changes_format = ObjectChangesFormat::Long;
+    }
+    if let Ok(DataType::Datetime(unit, tz)) = ocel_dfs
+        .events
+        .column(OCEL_EVENT_TIMESTAMP_KEY)
+        .map(|c| c.dtype())
+    {
+        options.timezone = TimezoneOption::from_polars_timezone(tz.as_ref());
+        options.timestamps.unit = TimestampUnit::from_polars(*unit);
+    }
+    options
+}
+
+pub fn ocel_dfs_from_py(mut ocel_dfs: HashMap<String, PyDataFrame>) -> PyResult<OCEL2DataFrames> {
+    let mut take = |key: &str| {
+        ocel_dfs
+            .remove(key)
+            .map(|df| df.0)
+            .ok_or_else(|| PyValueError::new_err(format!("Missing OCEL DataFrame '{key}'")))
+    };
+    Ok(OCEL2DataFrames {
+        events: take("events")?,
+        objects: take("objects")?,
+        o2o: take("o2o")?,
+        e2o: take("relations")?,
+        object_changes: take("object_changes")?,
+    })
+}
+
+/// Reconstruct an [OCEL] from the dict of DataFrames passed from Python
+pub fn ocel_from_py(ocel_dfs: HashMap<String, PyDataFrame>) -> PyResult<OCEL> {
+    let ocel_dfs = ocel_dfs_from_py(ocel_dfs)?;
+    ocel_dfs_to_ocel(&ocel_dfs)
+        .map_err(|e| PyValueError::new_err(format!("Failed to convert DataFrames to OCEL: {e:?}")))
+}
+
+/// Parse a timestamp passed from Python
+///
+/// Accepts RFC 3339 timestamps as well as timestamps/dates without offset (interpreted as UTC)
+pub fn parse_timestamp(s: &str) -> Result<DateTime<FixedOffset>, String> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt);
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, format) {
+            return Ok(dt.and_utc().fixed_offset());
+        }
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map(|d| d.and_hms_opt(0, 0, 0).unwrap().and_utc().fixed_offset())
+        .map_err(|_| format!("Invalid timestamp '{s}'"))
+}
+
+/// Parse the time unit and out-of-range policy passed from Python
+pub(crate) fn parse_timestamp_options(
+    time_unit: Option<String>,
+    out_of_range: Option<String>,
+) -> PyResult<TimestampOptions> {
+    Ok(TimestampOptions {
+        unit: parse_option(time_unit)?,
+        out_of_range: parse_option(out_of_range)?,
+    })
+}
+
+fn parse_df_options(
+    object_changes_format: Option<String>,
+    initial_attributes: Option<String>,
+    timezone: Option<String>,
+    time_unit: Option<String>,
+    out_of_range: Option<String>,
+) -> PyResult<OCEL2DataFrameOptions> {
+    let timezone: TimezoneOption = parse_option(timezone)?;
+    timezone
+        .to_polars_timezone()
+        .map_err(|e| PyValueError::new_err(format!("Invalid timezone: {e:?}")))?;
+    Ok(OCEL2DataFrameOptions {
+        object_changes_format: parse_option(object_changes_format)?,
+        initial_attributes: parse_option(initial_attributes)?,
+        timezone,
+        timestamps: parse_timestamp_options(time_unit, out_of_range)?,
+    })
+}
+
+fn type_filter(
+    object_types: Option<Vec<String>>,
+    exclude_object_types: Option<Vec<String>>,
+    event_types: Option<Vec<String>>,
+    exclude_event_types: Option<Vec<String>>,
+) -> OCELTypeFilter {
+    OCELTypeFilter {
+        object_types: object_types.map(HashSet::from_iter),
+        exclude_object_types: exclude_object_types
+            .map(HashSet::from_iter)
+            .unwrap_or_default(),
+        event_types: event_types.map(HashSet::from_iter),
+        exclude_event_types: exclude_event_types
+            .map(HashSet::from_iter)
+            .unwrap_or_default(),
+    }
+}
+
+/// Convert an [OCEL] to the dict of DataFrames returned to Python
+fn ocel_to_py(
+    ocel: &OCEL,
+    options: &OCEL2DataFrameOptions,
+    strict: Option<bool>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    if strict.is_some_and(|a| a) {
+        ensure_valid_ocel(ocel)?;
+    }
+    let ocel_dfs = ocel2_to_df_with(ocel, options)
+        .map_err(|e| PyValueError::new_err(format!("Failed to convert OCEL to DataFrames: {e}")))?;
+    Ok(ocel_dfs_to_py(ocel_dfs))
+}
+
+/// Import an OCEL and convert it to the dict of DataFrames returned to Python
+///
+/// If a `cache_dir` is given, the DataFrames are cached as Parquet files (keyed on file path, modification time, size and options)
+fn import_ocel_to_py(
+    path: &str,
+    load: fn(&str) -> PyResult<OCEL>,
+    options: &OCEL2DataFrameOptions,
+    filter: &OCELTypeFilter,
+    strict: Option<bool>,
+    cache_dir: Option<&str>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let Some(cache_dir) = cache_dir else {
+        let mut ocel = load(path)?;
+        filter_ocel_types(&mut ocel, filter);
+        return ocel_to_py(&ocel, options, strict);
+    };
+    let key = cache_key(
+        path,
+        &format!("ocel:{options:?}:{strict:?}:{}", filter.to_key()),
+    )
+    .map_err(|e| PyValueError::new_err(format!("Failed to access {path}: {e}")))?;
+    let cached: Option<HashMap<String, PyDataFrame>> = OCEL_DATAFRAME_NAMES
+        .iter()
+        .map(|name| {
+            load_cached_df(cache_dir, &key, name).map(|(df, _)| (name.to_string(), PyDataFrame(df)))
+        })
+        .collect();
+    if let Some(ocel_dfs) = cached {
+        return Ok(ocel_dfs);
+    }
+    let mut ocel = load(path)?;
+    filter_ocel_types(&mut ocel, filter);
+    let mut ocel_dfs = ocel_to_py(&ocel, options, strict)?;
+    for (name, df) in ocel_dfs.iter_mut() {
+        if let Err(e) = store_cached_df(cache_dir, &key, name, &mut df.0, Vec::new()) {
+            warn_py(&format!("Failed to write cache: {e}"))?;
+            break;
+        }
+    }
+    Ok(ocel_dfs)
+}
+
+/// Import an OCEL XML file (OCEL 2.0, or OCEL 1.0 for `.xmlocel` files and files with an XML-OCEL `log` root element)
+fn load_ocel_xml(path: &str) -> PyResult<OCEL> {
+    if path.ends_with(".xmlocel") || is_ocel1_xml_file(path) {
+        return import_ocel1_xml_from_path(path).map_err(PyValueError::new_err);
+    }
+    Ok(import_ocel_xml_file_with(
+        path,
         OCELImportOptions {
             verbose: false,
             ..Default::default()
         },
+    ))
+}
+
+/// Import an OCEL JSON file (OCEL 2.0, or OCEL 1.0 for `.jsonocel` files and files that are no valid OCEL 2.0 JSON)
+fn load_ocel_json(path: &str) -> PyResult<OCEL> {
+    if path.ends_with(".jsonocel") {
+        return import_ocel1_json_from_path(path).map_err(PyValueError::new_err);
+    }
+    import_ocel_json_from_path(path).or_else(|e| {
+        import_ocel1_json_from_path(path)
+            .map_err(|_| PyValueError::new_err(format!("Failed to import OCEL JSON: {e:?}")))
+    })
+}
+
+/// Import an OCEL XML or JSON file (based on the file extension)
+pub fn import_ocel_from_path(path: &str) -> PyResult<OCEL> {
+    if path.ends_with(".json") || path.ends_with(".jsonocel") {
+        load_ocel_json(path)
+    } else {
+        load_ocel_xml(path)
+    }
+}
+
+///
+/// Import an OCEL2 XML event log
+///
+/// OCEL 1.0 XML-OCEL files (`.xmlocel`, or `.xml` files with a `log` root element) are upgraded to OCEL 2.0
+///
+/// * `path` - The filepath of the .xml (or .xmlocel) file to import
+/// * `object_changes_format` - Optional layout of the `object_changes` DataFrame: `"wide"` (default) or `"long"`
+/// * `initial_attributes` - Optional policy for the initial attribute values in the `objects` DataFrame: `"epoch"`, `"earliest"` (default) or `"first_event"`
+/// * `strict` - Optional flag to fail if the OCEL has referential integrity or schema issues (see `validate_ocel_path_rs`)
+/// * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+/// * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+/// * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+/// * `object_types` - Optional list of object types to keep (all other objects are removed)
+/// * `exclude_object_types` - Optional list of object types to remove
+/// * `event_types` - Optional list of event types to keep (all other events are removed)
+/// * `exclude_event_types` - Optional list of event types to remove
+/// * `cache_dir` - Optional directory for caching the imported DataFrames as Parquet (keyed on file path, modification time, size and import options)
+///
+#[pyfunction]
+#[pyo3(signature = (path, object_changes_format=None, initial_attributes=None, strict=None, timezone=None, time_unit=None, out_of_range=None, object_types=None, exclude_object_types=None, event_types=None, exclude_event_types=None, cache_dir=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn import_ocel_xml_rs(
+    path: String,
+    object_changes_format: Option<String>,
+    initial_attributes: Option<String>,
+    strict: Option<bool>,
+    timezone: Option<String>,
+    time_unit: Option<String>,
+    out_of_range: Option<String>,
+    object_types: Option<Vec<String>>,
+    exclude_object_types: Option<Vec<String>>,
+    event_types: Option<Vec<String>>,
+    exclude_event_types: Option<Vec<String>>,
+    cache_dir: Option<String>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let options = parse_df_options(
+        object_changes_format,
+        initial_attributes,
+        timezone,
+        time_unit,
+        out_of_range,
+    )?;
+    let filter = type_filter(
+        object_types,
+        exclude_object_types,
+        event_types,
+        exclude_event_types,
     );
-    let ocel_dfs = ocel2_to_df(&ocel);
-    Ok(ocel_dfs_to_py(ocel_dfs))
+    import_ocel_to_py(
+        &path,
+        load_ocel_xml,
+        &options,
+        &filter,
+        strict,
+        cache_dir.as_deref(),
+    )
 }
 
+///
+/// Import an OCEL2 JSON event log
+///
+/// OCEL 1.0 JSON-OCEL files (`.jsonocel`) are upgraded to OCEL 2.0
+///
+/// * `path` - The filepath of the .json (or .jsonocel) file to import
+/// * `object_changes_format` - Optional layout of the `object_changes` DataFrame: `"wide"` (default) or `"long"`
+/// * `initial_attributes` - Optional policy for the initial attribute values in the `objects` DataFrame: `"epoch"`, `"earliest"` (default) or `"first_event"`
+/// * `strict` - Optional flag to fail if the OCEL has referential integrity or schema issues (see `validate_ocel_path_rs`)
+/// * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` (UTC wall time) or a named timezone (e.g., `"Europe/Berlin"`)
+/// * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+/// * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+/// * `object_types` - Optional list of object types to keep (all other objects are removed)
+/// * `exclude_object_types` - Optional list of object types to remove
+/// * `event_types` - Optional list of event types to keep (all other events are removed)
+/// * `exclude_event_types` - Optional list of event types to remove
+/// * `cache_dir` - Optional directory for caching the imported DataFrames as Parquet (keyed on file path, modification time, size and import options)
+///
 #[pyfunction]
-pub fn import_ocel_json_rs(path: String) -> PyResult<HashMap<String, PyDataFrame>> {
-    let ocel = import_ocel_json_from_path(&path).unwrap();
-    let ocel_dfs = ocel2_to_df(&ocel);
-    Ok(ocel_dfs_to_py(ocel_dfs))
+#[pyo3(signature = (path, object_changes_format=None, initial_attributes=None, strict=None, timezone=None, time_unit=None, out_of_range=None, object_types=None, exclude_object_types=None, event_types=None, exclude_event_types=None, cache_dir=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn import_ocel_json_rs(
+    path: String,
+    object_changes_format: Option<String>,
+    initial_attributes: Option<String>,
+    strict: Option<bool>,
+    timezone: Option<String>,
+    time_unit: Option<String>,
+    out_of_range: Option<String>,
+    object_types: Option<Vec<String>>,
+    exclude_object_types: Option<Vec<String>>,
+    event_types: Option<Vec<String>>,
+    exclude_event_types: Option<Vec<String>>,
+    cache_dir: Option<String>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let options = parse_df_options(
+        object_changes_format,
+        initial_attributes,
+        timezone,
+        time_unit,
+        out_of_range,
+    )?;
+    let filter = type_filter(
+        object_types,
+        exclude_object_types,
+        event_types,
+        exclude_event_types,
+    );
+    import_ocel_to_py(
+        &path,
+        load_ocel_json,
+        &options,
+        &filter,
+        strict,
+        cache_dir.as_deref(),
+    )
+}
+
+///
+/// Export an OCEL to an OCEL2 XML or JSON file (based on the file extension)
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+/// * `path` - The filepath of the .xml or .json file to export to
+///
+#[pyfunction]
+pub fn export_ocel_rs(ocel: HashMap<String, PyDataFrame>, path: String) -> PyResult<()> {
+    let ocel = ocel_from_py(ocel)?;
+    export_ocel(&ocel, &path)
+}
+
+/// Export an [OCEL] to an OCEL2 XML or JSON file (based on the file extension)
+pub fn export_ocel(ocel: &OCEL, path: &str) -> PyResult<()> {
+    if path.ends_with(".json") {
+        export_ocel_json_path(ocel, path)
+            .map_err(|e| PyTypeError::new_err(format!("Failed to export OCEL JSON: {e:?}")))
+    } else {
+        export_ocel_xml_path(ocel, path)
+            .map_err(|e| PyTypeError::new_err(format!("Failed to export OCEL XML: {e:?}")))
+    }
 }
@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+
+use polars::prelude::*;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::analysis::{column_seconds, column_strings};
+
+use super::{
+    OCEL_EVENT_ID_KEY, OCEL_EVENT_TIMESTAMP_KEY, OCEL_EVENT_TYPE_KEY, OCEL_OBJECT_ID_KEY,
+    OCEL_OBJECT_TYPE_KEY,
+};
+
+/// Assigns consecutive ids to strings
+#[derive(Default)]
+struct Interner {
+    ids: HashMap<String, usize>,
+    values: Vec<String>,
+}
+
+impl Interner {
+    fn intern(&mut self, value: String) -> usize {
+        if let Some(id) = self.ids.get(&value) {
+            return *id;
+        }
+        self.values.push(value.clone());
+        self.ids.insert(value, self.values.len() - 1);
+        self.values.len() - 1
+    }
+}
+
+/// Frequencies of an activity (node) of an object-centric directly-follows graph
+#[derive(Debug, Default)]
+struct OCDFGNode {
+    events: HashSet<usize>,
+    objects: HashSet<usize>,
+    total_objects: usize,
+    start_objects: usize,
+    end_objects: usize,
+}
+
+/// Frequencies of a directly-follows edge of an object-centric directly-follows graph
+#[derive(Debug, Default)]
+struct OCDFGEdge {
+    event_pairs: HashSet<(usize, usize)>,
+    objects: HashSet<usize>,
+    total_objects: usize,
+}
+
+///
+/// Discover the object-centric directly-follows graph from the E2O relations of an OCEL (the `relations` [DataFrame])
+///
+/// For every object type, the events of each object (ordered by timestamp) induce directly-follows edges between their event types.
+///
+/// Returns a nodes [DataFrame] (`ocel:type`, `ocel:activity`, `events`, `unique_objects`, `total_objects`, `start_objects`, `end_objects`)
+/// and an edges [DataFrame] (`ocel:type`, `source`, `target`, `events`, `unique_objects`, `total_objects`), where
+/// * `events` is the number of distinct events (for nodes) or event pairs (for edges),
+/// * `unique_objects` is the number of distinct objects,
+/// * `total_objects` is the number of (event, object) or (event pair, object) occurrences, and
+/// * `start_objects`/`end_objects` is the number of objects whose first/last event has the activity
+///
+pub fn discover_ocdfg(relations: &DataFrame) -> PolarsResult<(DataFrame, DataFrame)> {
+    let event_ids = column_strings(relations, OCEL_EVENT_ID_KEY)?;
+    let activities = column_strings(relations, OCEL_EVENT_TYPE_KEY)?;
+    let timestamps = column_seconds(relations, OCEL_EVENT_TIMESTAMP_KEY)?;
+    let object_ids = column_strings(relations, OCEL_OBJECT_ID_KEY)?;
+    let object_types = column_strings(relations, OCEL_OBJECT_TYPE_KEY)?;
+
+    let mut events = Interner::default();
+    let mut activity_names = Interner::default();
+    let mut type_names = Interner::default();
+    let mut objects = Interner::default();
+    // Object type and (timestamp, event, activity) of the events of each object
+    let mut object_events: Vec<(usize, Vec<(f64, usize, usize)>)> = Vec::new();
+    for ((((eid, activity), timestamp), oid), object_type) in event_ids
+        .into_iter()
+        .zip(activities)
+        .zip(timestamps)
+        .zip(object_ids)
+        .zip(object_types)
+    {
+        let object = objects.intern(oid);
+        if object == object_events.len() {
+            object_events.push((type_names.intern(object_type), Vec::new()));
+        }
+        object_events[object].1.push((
+            timestamp.unwrap_or_default(),
+            events.intern(eid),
+            activity_names.intern(activity),
+        ));
+    }
+
+    let mut nodes: HashMap<(usize, usize), OCDFGNode> = HashMap::new();
+    let mut edges: HashMap<(usize, usize, usize), OCDFGEdge> = HashMap::new();
+    for (object, (object_type, mut evs)) in object_events.into_iter().enumerate() {
+        // Events related to the same object with multiple qualifiers are only considered once
+        let mut seen = HashSet::new();
+        evs.retain(|(_, event, _)| seen.insert(*event));
+        // Stable sort keeps the order of events with equal timestamps
+        evs.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (i, (_, event, activity)) in evs.iter().enumerate() {
+            let node = nodes.entry((object_type, *activity)).or_default();
+            node.events.insert(*event);
+            node.objects.insert(object);
+            node.total_objects += 1;
+            if i == 0 {
+                node.start_objects += 1;
+            }
+            if i == evs.len() - 1 {
+                node.end_objects += 1;
+            }
+        }
+        for pair in evs.windows(2) {
+            let (_, e1, a1) = pair[0];
+            let (_, e2, a2) = pair[1];
+            let edge = edges.entry((object_type, a1, a2)).or_default();
+            edge.event_pairs.insert((e1, e2));
+            edge.objects.insert(object);
+            edge.total_objects += 1;
+        }
+    }
+
+    let type_name = |t: usize| type_names.values[t].as_str();
+    let activity_name = |a: usize| activity_names.values[a].as_str();
+    let mut nodes: Vec<_> = nodes.into_iter().collect();
+    nodes.sort_by(|((ta, aa), _), ((tb, ab), _)| {
+        (type_name(*ta), activity_name(*aa)).cmp(&(type_name(*tb), activity_name(*ab)))
+    });
+    let nodes_df = DataFrame::new(vec![
+        Column::new(
+            OCEL_OBJECT_TYPE_KEY.into(),
+            nodes.iter().map(|((t, _), _)| type_name(*t)).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            OCEL_EVENT_TYPE_KEY.into(),
+            nodes.iter().map(|((_, a), _)| activity_name(*a)).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "events".into(),
+            nodes.iter().map(|(_, n)| n.events.len() as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "unique_objects".into(),
+            nodes.iter().map(|(_, n)| n.objects.len() as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "total_objects".into(),
+            nodes.iter().map(|(_, n)| n.total_objects as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "start_objects".into(),
+            nodes.iter().map(|(_, n)| n.start_objects as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "end_objects".into(),
+            nodes.iter().map(|(_, n)| n.end_objects as u64).collect::<Vec<_>>(),
+        ),
+    ])?;
+
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort_by(|((ta, a1, a2), _), ((tb, b1, b2), _)| {
+        (type_name(*ta), activity_name(*a1), activity_name(*a2)).cmp(&(
+            type_name(*tb),
+            activity_name(*b1),
+            activity_name(*b2),
+        ))
+    });
+    let edges_df = DataFrame::new(vec![
+        Column::new(
+            OCEL_OBJECT_TYPE_KEY.into(),
+            edges.iter().map(|((t, _, _), _)| type_name(*t)).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "source".into(),
+            edges.iter().map(|((_, a, _), _)| activity_name(*a)).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "target".into(),
+            edges.iter().map(|((_, _, b), _)| activity_name(*b)).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "events".into(),
+            edges.iter().map(|(_, e)| e.event_pairs.len() as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "unique_objects".into(),
+            edges.iter().map(|(_, e)| e.objects.len() as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "total_objects".into(),
+            edges.iter().map(|(_, e)| e.total_objects as u64).collect::<Vec<_>>(),
+        ),
+    ])?;
+    Ok((nodes_df, edges_df))
+}
+
+///
+/// Discover the object-centric directly-follows graph of an OCEL
+///
+/// Returns a dict with the DataFrames `nodes` (`ocel:type`, `ocel:activity`, `events`, `unique_objects`, `total_objects`, `start_objects`, `end_objects`)
+/// and `edges` (`ocel:type`, `source`, `target`, `events`, `unique_objects`, `total_objects`)
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+///
+#[pyfunction]
+pub fn discover_ocdfg_rs(
+    ocel: HashMap<String, PyDataFrame>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let relations = ocel
+        .get("relations")
+        .ok_or_else(|| PyValueError::new_err("Missing OCEL DataFrame 'relations'"))?;
+    let (nodes, edges) = discover_ocdfg(&relations.0)
+        .map_err(|e| PyValueError::new_err(format!("Failed to discover OCDFG: {e:?}")))?;
+    Ok(HashMap::from([
+        ("nodes".to_string(), PyDataFrame(nodes)),
+        ("edges".to_string(), PyDataFrame(edges)),
+    ]))
+}
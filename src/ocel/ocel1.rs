@@ -0,0 +1,306 @@
+//! Import of OCEL 1.0 files (JSON-OCEL and XML-OCEL), upgraded to the OCEL 2.0 [OCEL] struct
+//!
+//! Objects of OCEL 1.0 logs have no O2O relationships, E2O relationships have an empty qualifier
+//! and the object attributes (`ocel:ovmap`) become initial attribute values (at the Unix epoch).
+use std::{fs::File, io::BufReader};
+
+use chrono::DateTime;
+use process_mining::{
+    ocel::ocel_struct::{
+        OCELAttributeValue, OCELEvent, OCELEventAttribute, OCELObject, OCELObjectAttribute,
+        OCELRelationship,
+    },
+    OCEL,
+};
+use quick_xml::events::{BytesStart, Event};
+use serde_json::Value;
+
+use super::{df_to_ocel::types_from_attributes, parse_timestamp};
+
+/// Derive the type declarations of an upgraded OCEL from its events and objects
+fn ocel_from_events_and_objects(events: Vec<OCELEvent>, objects: Vec<OCELObject>) -> OCEL {
+    let event_types = types_from_attributes(
+        events.iter().map(|e| &e.event_type),
+        events
+            .iter()
+            .flat_map(|e| e.attributes.iter().map(move |a| (&e.event_type, &a.name, &a.value))),
+    );
+    let object_types = types_from_attributes(
+        objects.iter().map(|o| &o.object_type),
+        objects
+            .iter()
+            .flat_map(|o| o.attributes.iter().map(move |a| (&o.object_type, &a.name, &a.value))),
+    );
+    OCEL {
+        event_types,
+        object_types,
+        events,
+        objects,
+    }
+}
+
+fn json_value_to_ocel_attribute_val(val: &Value) -> OCELAttributeValue {
+    match val {
+        Value::String(s) => OCELAttributeValue::String(s.clone()),
+        Value::Bool(b) => OCELAttributeValue::Boolean(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => OCELAttributeValue::Integer(i),
+            None => n
+                .as_f64()
+                .map(OCELAttributeValue::Float)
+                .unwrap_or(OCELAttributeValue::Null),
+        },
+        Value::Null => OCELAttributeValue::Null,
+        other => OCELAttributeValue::String(other.to_string()),
+    }
+}
+
+fn json_str<'a>(val: &'a Value, key: &str) -> Result<&'a str, String> {
+    val.get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("Missing '{key}'"))
+}
+
+///
+/// Parse an OCEL 1.0 JSON-OCEL log
+///
+pub fn parse_ocel1_json(json: &Value) -> Result<OCEL, String> {
+    let events = json
+        .get("ocel:events")
+        .and_then(Value::as_object)
+        .ok_or_else(|| "Missing 'ocel:events'".to_string())?
+        .iter()
+        .map(|(id, ev)| {
+            Ok(OCELEvent {
+                id: id.clone(),
+                event_type: json_str(ev, "ocel:activity")?.to_string(),
+                time: parse_timestamp(json_str(ev, "ocel:timestamp")?)?,
+                attributes: ev
+                    .get("ocel:vmap")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, value)| OCELEventAttribute {
+                        name: name.clone(),
+                        value: json_value_to_ocel_attribute_val(value),
+                    })
+                    .collect(),
+                relationships: ev
+                    .get("ocel:omap")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .map(|object_id| OCELRelationship {
+                        object_id: object_id.to_string(),
+                        qualifier: String::new(),
+                    })
+                    .collect(),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let objects = json
+        .get("ocel:objects")
+        .and_then(Value::as_object)
+        .ok_or_else(|| "Missing 'ocel:objects'".to_string())?
+        .iter()
+        .map(|(id, obj)| {
+            Ok(OCELObject {
+                id: id.clone(),
+                object_type: json_str(obj, "ocel:type")?.to_string(),
+                attributes: obj
+                    .get("ocel:ovmap")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, value)| OCELObjectAttribute {
+                        name: name.clone(),
+                        value: json_value_to_ocel_attribute_val(value),
+                        time: DateTime::UNIX_EPOCH.fixed_offset(),
+                    })
+                    .collect(),
+                relationships: Vec::new(),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(ocel_from_events_and_objects(events, objects))
+}
+
+///
+/// Import an OCEL 1.0 JSON-OCEL file (`.jsonocel`)
+///
+pub fn import_ocel1_json_from_path(path: &str) -> Result<OCEL, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {path}: {e}"))?;
+    let json: Value = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("Failed to parse JSON: {e}"))?;
+    parse_ocel1_json(&json)
+}
+
+/// Current element of an XML-OCEL log
+enum XMLOCELElement {
+    Event(OCELEvent),
+    Object(OCELObject),
+}
+
+/// `key` and `value` attributes of an XML-OCEL attribute element
+fn xml_key_value(e: &BytesStart) -> Result<(String, String), String> {
+    let mut key = String::new();
+    let mut value = String::new();
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| format!("Invalid XML attribute: {e}"))?;
+        let attr_value = attr
+            .unescape_value()
+            .map_err(|e| format!("Invalid XML attribute value: {e}"))?
+            .to_string();
+        match attr.key.as_ref() {
+            b"key" => key = attr_value,
+            b"value" => value = attr_value,
+            _ => {}
+        }
+    }
+    Ok((key, value))
+}
+
+fn xml_value_to_ocel_attribute_val(tag: &[u8], value: String) -> OCELAttributeValue {
+    match tag {
+        b"int" => value
+            .parse()
+            .map(OCELAttributeValue::Integer)
+            .unwrap_or(OCELAttributeValue::String(value)),
+        b"float" => value
+            .parse()
+            .map(OCELAttributeValue::Float)
+            .unwrap_or(OCELAttributeValue::String(value)),
+        b"boolean" => value
+            .parse()
+            .map(OCELAttributeValue::Boolean)
+            .unwrap_or(OCELAttributeValue::String(value)),
+        b"date" => parse_timestamp(&value)
+            .map(OCELAttributeValue::Time)
+            .unwrap_or(OCELAttributeValue::String(value)),
+        _ => OCELAttributeValue::String(value),
+    }
+}
+
+///
+/// Parse an OCEL 1.0 XML-OCEL log
+///
+pub fn parse_ocel1_xml<R: std::io::BufRead>(reader: R) -> Result<OCEL, String> {
+    let mut reader = quick_xml::Reader::from_reader(reader);
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+    let mut events = Vec::new();
+    let mut objects = Vec::new();
+    let mut current: Option<XMLOCELElement> = None;
+    // Key of the list (`omap`, `vmap` or `ovmap`) the parser is currently in
+    let mut current_list: Option<String> = None;
+    loop {
+        let event = reader
+            .read_event_into(&mut buf)
+            .map_err(|e| format!("Invalid XML: {e}"))?;
+        let is_empty = matches!(event, Event::Empty(_));
+        match event {
+            Event::Start(e) | Event::Empty(e) => match e.name().as_ref() {
+                b"event" => {
+                    current = Some(XMLOCELElement::Event(OCELEvent {
+                        id: String::new(),
+                        event_type: String::new(),
+                        time: DateTime::UNIX_EPOCH.fixed_offset(),
+                        attributes: Vec::new(),
+                        relationships: Vec::new(),
+                    }))
+                }
+                b"object" => {
+                    current = Some(XMLOCELElement::Object(OCELObject {
+                        id: String::new(),
+                        object_type: String::new(),
+                        attributes: Vec::new(),
+                        relationships: Vec::new(),
+                    }))
+                }
+                b"list" if !is_empty => current_list = Some(xml_key_value(&e)?.0),
+                b"list" => {}
+                tag => {
+                    let (key, value) = xml_key_value(&e)?;
+                    match (&mut current, current_list.as_deref()) {
+                        (Some(XMLOCELElement::Event(ev)), None) => match key.as_str() {
+                            "id" => ev.id = value,
+                            "activity" => ev.event_type = value,
+                            "timestamp" => ev.time = parse_timestamp(&value)?,
+                            _ => {}
+                        },
+                        (Some(XMLOCELElement::Event(ev)), Some("omap")) => {
+                            ev.relationships.push(OCELRelationship {
+                                object_id: value,
+                                qualifier: String::new(),
+                            })
+                        }
+                        (Some(XMLOCELElement::Event(ev)), Some("vmap")) => {
+                            ev.attributes.push(OCELEventAttribute {
+                                name: key,
+                                value: xml_value_to_ocel_attribute_val(tag, value),
+                            })
+                        }
+                        (Some(XMLOCELElement::Object(obj)), None) => match key.as_str() {
+                            "id" => obj.id = value,
+                            "type" => obj.object_type = value,
+                            _ => {}
+                        },
+                        (Some(XMLOCELElement::Object(obj)), Some("ovmap")) => {
+                            obj.attributes.push(OCELObjectAttribute {
+                                name: key,
+                                value: xml_value_to_ocel_attribute_val(tag, value),
+                                time: DateTime::UNIX_EPOCH.fixed_offset(),
+                            })
+                        }
+                        _ => {}
+                    }
+                }
+            },
+            Event::End(e) => match e.name().as_ref() {
+                b"event" | b"object" => match current.take() {
+                    Some(XMLOCELElement::Event(ev)) => events.push(ev),
+                    Some(XMLOCELElement::Object(obj)) => objects.push(obj),
+                    None => {}
+                },
+                b"list" => current_list = None,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(ocel_from_events_and_objects(events, objects))
+}
+
+///
+/// Check whether an XML document is an OCEL 1.0 XML-OCEL log (i.e., its root element is `log`, not `ocel` as in OCEL 2.0)
+///
+pub fn is_ocel1_xml<R: std::io::BufRead>(reader: R) -> bool {
+    let mut reader = quick_xml::Reader::from_reader(reader);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => return e.name().as_ref() == b"log",
+            Ok(Event::Eof) | Err(_) => return false,
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+///
+/// Check whether the file at `path` is an OCEL 1.0 XML-OCEL log (see [is_ocel1_xml])
+///
+pub fn is_ocel1_xml_file(path: &str) -> bool {
+    File::open(path).is_ok_and(|file| is_ocel1_xml(BufReader::new(file)))
+}
+
+///
+/// Import an OCEL 1.0 XML-OCEL file (`.xmlocel`, or `.xml` with a `log` root element)
+///
+pub fn import_ocel1_xml_from_path(path: &str) -> Result<OCEL, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {path}: {e}"))?;
+    parse_ocel1_xml(BufReader::new(file))
+}
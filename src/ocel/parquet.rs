@@ -0,0 +1,275 @@
+//! Parquet serialization of OCEL 2.0
+//!
+//! An OCEL is stored as a directory of Parquet files:
+//! * `events.parquet`, `objects.parquet`, `o2o.parquet`, `relations.parquet` and `object_changes.parquet`
+//!   contain the DataFrames as returned by the OCEL import functions (with `object_changes` in wide or long format)
+//! * `types.parquet` contains the type declarations with one row per declared attribute (or one row with null attribute
+//!   for types without attributes) and the columns `ocel:kind` (`event` or `object`), `ocel:type`, `ocel:attribute` and `ocel:value_type`
+//!
+//! Every file carries the layout version in its key-value metadata (`rustxes:ocel_parquet_version`).
+//! Only directories are supported as target (an OCEL consists of several tables with different schemas,
+//! which do not fit a single Parquet file).
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use polars::prelude::*;
+use process_mining::{
+    ocel::ocel_struct::{OCELType, OCELTypeAttribute},
+    OCEL,
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    pyfunction, PyResult,
+};
+use pyo3_polars::PyDataFrame;
+
+use crate::cache::{read_parquet_with_metadata, write_parquet_with_metadata};
+
+use super::{
+    export_ocel, import_ocel_from_path, ocel2_to_df, ocel_dfs_from_py, ocel_dfs_to_ocel,
+    ocel_dfs_to_py,
+    validate::{apply_declared_types, DeclaredTypes},
+    OCEL2DataFrames, OCEL_OBJECT_TYPE_KEY,
+};
+
+/// Key-value metadata key storing the version of the Parquet layout
+pub const OCEL_PARQUET_VERSION_KEY: &str = "rustxes:ocel_parquet_version";
+pub const OCEL_PARQUET_VERSION: &str = "1";
+pub const OCEL_TYPE_KIND_KEY: &str = "ocel:kind";
+pub const OCEL_TYPE_ATTRIBUTE_KEY: &str = "ocel:attribute";
+pub const OCEL_TYPE_VALUE_TYPE_KEY: &str = "ocel:value_type";
+const TYPES_TABLE: &str = "types";
+
+fn table_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.parquet"))
+}
+
+///
+/// Convert the type declarations of an [OCEL] to a [DataFrame] (see module documentation for the layout)
+///
+pub fn ocel_types_to_df(ocel: &OCEL) -> PolarsResult<DataFrame> {
+    let mut kinds: Vec<&str> = Vec::new();
+    let mut type_names: Vec<&str> = Vec::new();
+    let mut attributes: Vec<Option<&str>> = Vec::new();
+    let mut value_types: Vec<Option<&str>> = Vec::new();
+    for (kind, types) in [("event", &ocel.event_types), ("object", &ocel.object_types)] {
+        for t in types {
+            if t.attributes.is_empty() {
+                kinds.push(kind);
+                type_names.push(&t.name);
+                attributes.push(None);
+                value_types.push(None);
+            }
+            for a in &t.attributes {
+                kinds.push(kind);
+                type_names.push(&t.name);
+                attributes.push(Some(&a.name));
+                value_types.push(Some(&a.value_type));
+            }
+        }
+    }
+    DataFrame::new(vec![
+        Column::new(OCEL_TYPE_KIND_KEY.into(), kinds),
+        Column::new(OCEL_OBJECT_TYPE_KEY.into(), type_names),
+        Column::new(OCEL_TYPE_ATTRIBUTE_KEY.into(), attributes),
+        Column::new(OCEL_TYPE_VALUE_TYPE_KEY.into(), value_types),
+    ])
+}
+
+fn column_opt_strings(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<String>>> {
+    Ok(df
+        .column(name)?
+        .str()?
+        .into_iter()
+        .map(|s| s.map(|s| s.to_string()))
+        .collect())
+}
+
+///
+/// Read the event and object type declarations from a [DataFrame] created by [ocel_types_to_df]
+///
+pub fn ocel_types_from_df(df: &DataFrame) -> PolarsResult<(Vec<OCELType>, Vec<OCELType>)> {
+    let kinds = column_opt_strings(df, OCEL_TYPE_KIND_KEY)?;
+    let type_names = column_opt_strings(df, OCEL_OBJECT_TYPE_KEY)?;
+    let attributes = column_opt_strings(df, OCEL_TYPE_ATTRIBUTE_KEY)?;
+    let value_types = column_opt_strings(df, OCEL_TYPE_VALUE_TYPE_KEY)?;
+    let mut event_types: Vec<OCELType> = Vec::new();
+    let mut object_types: Vec<OCELType> = Vec::new();
+    let mut indices: HashMap<(bool, String), usize> = HashMap::new();
+    for (((kind, name), attribute), value_type) in
+        kinds.into_iter().zip(type_names).zip(attributes).zip(value_types)
+    {
+        let is_event = match kind.as_deref() {
+            Some("event") => true,
+            Some("object") => false,
+            kind => polars_bail!(ComputeError: "Invalid type kind {:?}", kind),
+        };
+        let name = name.unwrap_or_default();
+        let types = if is_event {
+            &mut event_types
+        } else {
+            &mut object_types
+        };
+        let index = *indices.entry((is_event, name.clone())).or_insert_with(|| {
+            types.push(OCELType {
+                name,
+                attributes: Vec::new(),
+            });
+            types.len() - 1
+        });
+        if let Some(attribute) = attribute {
+            types[index].attributes.push(OCELTypeAttribute {
+                name: attribute,
+                value_type: value_type.unwrap_or_default(),
+            });
+        }
+    }
+    Ok((event_types, object_types))
+}
+
+///
+/// Write OCEL DataFrames and type declarations as Parquet files to a directory
+///
+/// Fails if `dir` is an existing file or has a `.parquet` extension (only directories are supported)
+///
+pub fn write_ocel_dfs_parquet(
+    dir: &Path,
+    ocel_dfs: &mut OCEL2DataFrames,
+    types: &mut DataFrame,
+) -> PolarsResult<()> {
+    if dir.is_file() || dir.extension().is_some_and(|e| e == "parquet") {
+        polars_bail!(
+            ComputeError: "OCEL Parquet target {} must be a directory (one Parquet file is written per table)",
+            dir.display()
+        );
+    }
+    let metadata = || {
+        vec![(
+            OCEL_PARQUET_VERSION_KEY.to_string(),
+            OCEL_PARQUET_VERSION.to_string(),
+        )]
+    };
+    for (name, df) in [
+        ("events", &mut ocel_dfs.events),
+        ("objects", &mut ocel_dfs.objects),
+        ("o2o", &mut ocel_dfs.o2o),
+        ("relations", &mut ocel_dfs.e2o),
+        ("object_changes", &mut ocel_dfs.object_changes),
+        (TYPES_TABLE, types),
+    ] {
+        write_parquet_with_metadata(&table_path(dir, name), df, metadata())?;
+    }
+    Ok(())
+}
+
+///
+/// Write an [OCEL] (including its type declarations) as Parquet files to a directory
+///
+pub fn write_ocel_parquet(ocel: &OCEL, dir: &Path) -> PolarsResult<()> {
+    write_ocel_dfs_parquet(dir, &mut ocel2_to_df(ocel)?, &mut ocel_types_to_df(ocel)?)
+}
+
+///
+/// Read OCEL DataFrames and the type declarations [DataFrame] written by [write_ocel_dfs_parquet]
+///
+pub fn read_ocel_dfs_parquet(dir: &Path) -> PolarsResult<(OCEL2DataFrames, DataFrame)> {
+    let read = |name: &str| -> PolarsResult<DataFrame> {
+        let (df, metadata) = read_parquet_with_metadata(&table_path(dir, name))?;
+        match metadata.get(OCEL_PARQUET_VERSION_KEY).map(String::as_str) {
+            Some(OCEL_PARQUET_VERSION) => Ok(df),
+            version => polars_bail!(
+                ComputeError: "Unsupported OCEL Parquet layout version {:?} in {}", version, name
+            ),
+        }
+    };
+    Ok((
+        OCEL2DataFrames {
+            events: read("events")?,
+            objects: read("objects")?,
+            o2o: read("o2o")?,
+            e2o: read("relations")?,
+            object_changes: read("object_changes")?,
+        },
+        read(TYPES_TABLE)?,
+    ))
+}
+
+///
+/// Read an [OCEL] (including its type declarations) from Parquet files written by [write_ocel_dfs_parquet]
+///
+pub fn read_ocel_parquet(dir: &Path) -> PolarsResult<OCEL> {
+    let (ocel_dfs, types) = read_ocel_dfs_parquet(dir)?;
+    let mut ocel = ocel_dfs_to_ocel(&ocel_dfs)?;
+    let (event_types, object_types) = ocel_types_from_df(&types)?;
+    ocel.event_types = event_types;
+    ocel.object_types = object_types;
+    Ok(ocel)
+}
+
+///
+/// Convert an OCEL2 file (.xml or .json) to Parquet files
+///
+/// * `ocel_path` - The filepath of the OCEL2 file to convert
+/// * `path` - The directory the Parquet files should be written to
+///
+#[pyfunction]
+pub fn write_ocel_parquet_path_rs(ocel_path: String, path: String) -> PyResult<()> {
+    let ocel = import_ocel_from_path(&ocel_path)?;
+    write_ocel_parquet(&ocel, Path::new(&path))
+        .map_err(|e| PyTypeError::new_err(format!("Failed to write OCEL Parquet: {e:?}")))
+}
+
+///
+/// Write an OCEL given as dict of DataFrames to Parquet files
+///
+/// The DataFrames are stored as-is; type declarations are derived from the DataFrames unless declared types are given.
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+/// * `path` - The directory the Parquet files should be written to
+/// * `event_types` - Optional declared event types (event type → attribute name → attribute type, e.g., `"string"`, `"time"`, `"integer"`, `"float"` or `"boolean"`)
+/// * `object_types` - Optional declared object types (object type → attribute name → attribute type)
+///
+#[pyfunction]
+#[pyo3(signature = (ocel, path, event_types=None, object_types=None))]
+pub fn write_ocel_parquet_dfs_rs(
+    ocel: HashMap<String, PyDataFrame>,
+    path: String,
+    event_types: Option<DeclaredTypes>,
+    object_types: Option<DeclaredTypes>,
+) -> PyResult<()> {
+    let mut ocel_dfs = ocel_dfs_from_py(ocel)?;
+    let mut ocel = ocel_dfs_to_ocel(&ocel_dfs)
+        .map_err(|e| PyValueError::new_err(format!("Failed to convert DataFrames to OCEL: {e:?}")))?;
+    apply_declared_types(&mut ocel, event_types.as_ref(), object_types.as_ref());
+    ocel_types_to_df(&ocel)
+        .and_then(|mut types| write_ocel_dfs_parquet(Path::new(&path), &mut ocel_dfs, &mut types))
+        .map_err(|e| PyTypeError::new_err(format!("Failed to write OCEL Parquet: {e:?}")))
+}
+
+///
+/// Read an OCEL from Parquet files
+///
+/// Returns the OCEL as dict of DataFrames (see `import_ocel_xml_rs`)
+///
+/// * `path` - The directory containing the Parquet files
+/// * `export_path` - Optional filepath of a .xml or .json file the OCEL (including its type declarations) should be exported to
+///
+#[pyfunction]
+#[pyo3(signature = (path, export_path=None))]
+pub fn read_ocel_parquet_rs(
+    path: String,
+    export_path: Option<String>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let (ocel_dfs, types) = read_ocel_dfs_parquet(Path::new(&path))
+        .map_err(|e| PyValueError::new_err(format!("Failed to read OCEL Parquet: {e:?}")))?;
+    if let Some(export_path) = export_path {
+        let mut ocel = ocel_dfs_to_ocel(&ocel_dfs)
+            .map_err(|e| PyValueError::new_err(format!("Failed to convert DataFrames to OCEL: {e:?}")))?;
+        (ocel.event_types, ocel.object_types) = ocel_types_from_df(&types)
+            .map_err(|e| PyValueError::new_err(format!("Invalid type declarations: {e:?}")))?;
+        export_ocel(&ocel, &export_path)?;
+    }
+    Ok(ocel_dfs_to_py(ocel_dfs))
+}
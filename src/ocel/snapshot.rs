@@ -0,0 +1,316 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, FixedOffset};
+use polars::{prelude::*, series::Series};
+use process_mining::{
+    ocel::ocel_struct::{OCELAttributeValue, OCELObject},
+    OCEL,
+};
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::time::TimestampOptions;
+
+use super::{
+    ensure_timestamps_in_range, ocel_attribute_val_to_any_value, ocel_from_py,
+    parse_timestamp, parse_timestamp_options, OCEL_EVENT_ID_KEY,
+    OCEL_EVENT_TIMESTAMP_KEY, OCEL_EVENT_TYPE_KEY, OCEL_OBJECT_ID_KEY, OCEL_OBJECT_TYPE_KEY,
+    OCEL_QUALIFIER_KEY,
+    validate::{apply_declared_types, DeclaredTypes},
+};
+
+/// Attribute value history of a single object, sorted by time per attribute name
+pub(super) struct ObjectAttributeHistory<'a> {
+    values: HashMap<&'a str, Vec<(DateTime<FixedOffset>, &'a OCELAttributeValue)>>,
+}
+
+impl<'a> ObjectAttributeHistory<'a> {
+    pub(super) fn new(object: &'a OCELObject) -> Self {
+        let mut values: HashMap<&str, Vec<_>> = HashMap::new();
+        for oa in &object.attributes {
+            values
+                .entry(oa.name.as_str())
+                .or_default()
+                .push((oa.time, &oa.value));
+        }
+        // Stable sort: For equal timestamps, the value listed last takes precedence
+        values
+            .values_mut()
+            .for_each(|v| v.sort_by_key(|(time, _)| *time));
+        Self { values }
+    }
+
+    /// Latest value of the attribute `name` recorded at or before `at` (if any)
+    fn latest_at(&self, name: &str, at: &DateTime<FixedOffset>) -> Option<&'a OCELAttributeValue> {
+        self.values.get(name).and_then(|history| {
+            let idx = history.partition_point(|(time, _)| time <= at);
+            idx.checked_sub(1).map(|i| history[i].1)
+        })
+    }
+
+    /// Latest value of the attribute `name` recorded at or before `at` (or [OCELAttributeValue::Null])
+    fn value_at(&self, name: &str, at: &DateTime<FixedOffset>) -> &'a OCELAttributeValue {
+        self.latest_at(name, at)
+            .unwrap_or(&OCELAttributeValue::Null)
+    }
+
+    /// Latest values of all attributes recorded at or before `at`
+    pub(super) fn values_at(
+        &self,
+        at: &DateTime<FixedOffset>,
+    ) -> HashMap<&'a str, &'a OCELAttributeValue> {
+        self.values
+            .keys()
+            .filter_map(|name| self.latest_at(name, at).map(|v| (*name, v)))
+            .collect()
+    }
+
+    /// Earliest recorded values of all attributes
+    pub(super) fn earliest_values(&self) -> HashMap<&'a str, &'a OCELAttributeValue> {
+        self.values
+            .iter()
+            .filter_map(|(name, history)| history.first().map(|(_, v)| (*name, *v)))
+            .collect()
+    }
+}
+
+/// Sorted names of all object attributes (declared or used)
+fn object_attribute_names(ocel: &OCEL) -> Vec<String> {
+    let names: HashSet<&String> = ocel
+        .object_types
+        .iter()
+        .flat_map(|ot| ot.attributes.iter().map(|at| &at.name))
+        .chain(
+            ocel.objects
+                .iter()
+                .flat_map(|o| o.attributes.iter().map(|oa| &oa.name)),
+        )
+        .collect();
+    let mut names: Vec<String> = names.into_iter().cloned().collect();
+    names.sort();
+    names
+}
+
+/// Data type of an object attribute column, if the attribute is declared with the same type by all object types declaring it
+fn declared_attribute_dtype(ocel: &OCEL, name: &str, timestamps: &TimestampOptions) -> Option<DataType> {
+    let mut value_types = ocel
+        .object_types
+        .iter()
+        .flat_map(|ot| ot.attributes.iter())
+        .filter(|at| at.name == name)
+        .map(|at| at.value_type.as_str());
+    let value_type = value_types.next()?;
+    if !value_types.all(|t| t == value_type) {
+        return None;
+    }
+    match value_type {
+        "string" => Some(DataType::String),
+        "time" => Some(DataType::Datetime(timestamps.unit.to_polars(), None)),
+        "integer" => Some(DataType::Int64),
+        "float" => Some(DataType::Float64),
+        "boolean" => Some(DataType::Boolean),
+        _ => None,
+    }
+}
+
+/// Attribute value columns (typed according to the declared object types, otherwise according to the values)
+fn attribute_columns<'a>(
+    ocel: &OCEL,
+    names: &[String],
+    rows: impl Iterator<Item = (&'a ObjectAttributeHistory<'a>, DateTime<FixedOffset>)> + Clone,
+    timestamps: &TimestampOptions,
+) -> PolarsResult<Vec<Series>> {
+    names
+        .iter()
+        .map(|name| {
+            let values: Vec<AnyValue> = rows
+                .clone()
+                .map(|(history, at)| {
+                    ocel_attribute_val_to_any_value(history.value_at(name, &at), timestamps)
+                })
+                .collect();
+            match declared_attribute_dtype(ocel, name, timestamps) {
+                Some(dtype) => Series::from_any_values_and_dtype(name.into(), &values, &dtype, false),
+                None => Series::from_any_values(name.into(), &values, false),
+            }
+        })
+        .collect()
+}
+
+///
+/// Compute the state of all objects at a given point in time
+///
+/// Returns a [DataFrame] with one row per object, containing the object id, the object type and,
+/// for every object attribute, the latest value recorded at or before `at` (or null if there is none).
+/// Datetime values are converted according to `timestamps` (failing for out-of-range timestamps with [crate::time::OutOfRangePolicy::Error]).
+///
+pub fn object_snapshot(
+    ocel: &OCEL,
+    at: &DateTime<FixedOffset>,
+    timestamps: &TimestampOptions,
+) -> PolarsResult<DataFrame> {
+    ensure_timestamps_in_range(ocel, timestamps)?;
+    let histories: Vec<_> = ocel.objects.iter().map(ObjectAttributeHistory::new).collect();
+    let names = object_attribute_names(ocel);
+    let mut columns = vec![
+        Series::new(
+            OCEL_OBJECT_ID_KEY.into(),
+            ocel.objects.iter().map(|o| o.id.as_str()).collect::<Vec<_>>(),
+        ),
+        Series::new(
+            OCEL_OBJECT_TYPE_KEY.into(),
+            ocel.objects
+                .iter()
+                .map(|o| o.object_type.as_str())
+                .collect::<Vec<_>>(),
+        ),
+    ];
+    columns.extend(attribute_columns(
+        ocel,
+        &names,
+        histories.iter().map(|h| (h, *at)),
+        timestamps,
+    )?);
+    Ok(DataFrame::from_iter(columns))
+}
+
+///
+/// Enrich all E2O relations with the state of the related object at the time of the event
+///
+/// Returns a [DataFrame] with one row per E2O relation (with the same columns as the `relations` DataFrame)
+/// and, for every object attribute, the latest value of the related object recorded at or before the event timestamp.
+/// Relations to unknown objects have null attribute values.
+/// Datetime values are converted according to `timestamps` (failing for out-of-range timestamps with [crate::time::OutOfRangePolicy::Error]).
+///
+pub fn enrich_events_with_object_state(
+    ocel: &OCEL,
+    timestamps: &TimestampOptions,
+) -> PolarsResult<DataFrame> {
+    ensure_timestamps_in_range(ocel, timestamps)?;
+    let histories: HashMap<&str, ObjectAttributeHistory> = ocel
+        .objects
+        .iter()
+        .map(|o| (o.id.as_str(), ObjectAttributeHistory::new(o)))
+        .collect();
+    let obj_types: HashMap<&str, &str> = ocel
+        .objects
+        .iter()
+        .map(|o| (o.id.as_str(), o.object_type.as_str()))
+        .collect();
+    let empty_history = ObjectAttributeHistory {
+        values: HashMap::new(),
+    };
+    let names = object_attribute_names(ocel);
+    let mut rows: Vec<_> = ocel
+        .events
+        .iter()
+        .flat_map(|e| e.relationships.iter().map(move |r| (e, r)))
+        .collect();
+    rows.sort_by_key(|(e, _r)| e.time);
+
+    let mut columns = vec![
+        Series::new(
+            OCEL_EVENT_ID_KEY.into(),
+            rows.iter().map(|(e, _r)| e.id.as_str()).collect::<Vec<_>>(),
+        ),
+        Series::new(
+            OCEL_EVENT_TYPE_KEY.into(),
+            rows.iter()
+                .map(|(e, _r)| e.event_type.as_str())
+                .collect::<Vec<_>>(),
+        ),
+        Series::from_any_values(
+            OCEL_EVENT_TIMESTAMP_KEY.into(),
+            &rows
+                .iter()
+                .map(|(e, _r)| timestamps.to_any_value(&e.time))
+                .collect::<Vec<_>>(),
+            false,
+        )?,
+        Series::new(
+            OCEL_OBJECT_ID_KEY.into(),
+            rows.iter()
+                .map(|(_e, r)| r.object_id.as_str())
+                .collect::<Vec<_>>(),
+        ),
+        Series::new(
+            OCEL_OBJECT_TYPE_KEY.into(),
+            rows.iter()
+                .map(|(_e, r)| obj_types.get(r.object_id.as_str()).copied())
+                .collect::<Vec<_>>(),
+        ),
+        Series::new(
+            OCEL_QUALIFIER_KEY.into(),
+            rows.iter()
+                .map(|(_e, r)| r.qualifier.as_str())
+                .collect::<Vec<_>>(),
+        ),
+    ];
+    columns.extend(attribute_columns(
+        ocel,
+        &names,
+        rows.iter().map(|(e, r)| {
+            (
+                histories
+                    .get(r.object_id.as_str())
+                    .unwrap_or(&empty_history),
+                e.time,
+            )
+        }),
+        timestamps,
+    )?);
+    Ok(DataFrame::from_iter(columns))
+}
+
+///
+/// Compute the state of all objects of an OCEL at a given point in time
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+/// * `at` - The timestamp (RFC 3339/ISO 8601 string)
+/// * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+/// * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+/// * `object_types` - Optional declared object types (object type → attribute name → attribute type), used for the attribute columns
+///   instead of the types derived from the DataFrames
+///
+#[pyfunction]
+#[pyo3(signature = (ocel, at, time_unit=None, out_of_range=None, object_types=None))]
+pub fn object_snapshot_rs(
+    ocel: HashMap<String, PyDataFrame>,
+    at: String,
+    time_unit: Option<String>,
+    out_of_range: Option<String>,
+    object_types: Option<DeclaredTypes>,
+) -> PyResult<PyDataFrame> {
+    let timestamps = parse_timestamp_options(time_unit, out_of_range)?;
+    let mut ocel = ocel_from_py(ocel)?;
+    apply_declared_types(&mut ocel, None, object_types.as_ref());
+    let at = parse_timestamp(&at).map_err(PyValueError::new_err)?;
+    let snapshot = object_snapshot(&ocel, &at, &timestamps)
+        .map_err(|e| PyValueError::new_err(format!("Failed to compute object snapshot: {e}")))?;
+    Ok(PyDataFrame(snapshot))
+}
+
+///
+/// Enrich all E2O relations of an OCEL with the state of the related objects at the time of the event
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+/// * `time_unit` - Optional time unit of datetime columns: `"ns"` (default), `"us"` or `"ms"`
+/// * `out_of_range` - Optional handling of timestamps not representable with the time unit: `"error"` (default), `"null"` or `"clamp"`
+/// * `object_types` - Optional declared object types (object type → attribute name → attribute type), used for the attribute columns
+///   instead of the types derived from the DataFrames
+///
+#[pyfunction]
+#[pyo3(signature = (ocel, time_unit=None, out_of_range=None, object_types=None))]
+pub fn enrich_events_with_object_state_rs(
+    ocel: HashMap<String, PyDataFrame>,
+    time_unit: Option<String>,
+    out_of_range: Option<String>,
+    object_types: Option<DeclaredTypes>,
+) -> PyResult<PyDataFrame> {
+    let timestamps = parse_timestamp_options(time_unit, out_of_range)?;
+    let mut ocel = ocel_from_py(ocel)?;
+    apply_declared_types(&mut ocel, None, object_types.as_ref());
+    let enriched = enrich_events_with_object_state(&ocel, &timestamps)
+        .map_err(|e| PyValueError::new_err(format!("Failed to enrich events: {e}")))?;
+    Ok(PyDataFrame(enriched))
+}
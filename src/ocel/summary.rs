@@ -0,0 +1,140 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use polars::prelude::*;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+
+use crate::analysis::column_strings;
+
+use super::{
+    ocel_dfs_from_py, OCEL2DataFrames, OCEL_EVENT_ID_KEY, OCEL_EVENT_TYPE_KEY,
+    OCEL_OBJECT_ID_2_KEY, OCEL_OBJECT_ID_KEY, OCEL_OBJECT_TYPE_KEY,
+};
+
+/// Number of occurrences of every value of a string column as [DataFrame] (`name`, `count_name`), sorted by value
+fn value_counts_df(df: &DataFrame, name: &str, count_name: &str) -> PolarsResult<DataFrame> {
+    let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+    for value in column_strings(df, name)? {
+        *counts.entry(value).or_default() += 1;
+    }
+    DataFrame::new(vec![
+        Column::new(
+            name.into(),
+            counts.keys().map(String::as_str).collect::<Vec<_>>(),
+        ),
+        Column::new(count_name.into(), counts.values().copied().collect::<Vec<_>>()),
+    ])
+}
+
+///
+/// Histogram of the number of distinct related items of every element, grouped by the type of the element
+///
+/// `elements` are (element id, element type) pairs; elements without related items have degree 0.
+/// Returns a [DataFrame] with the columns `group_name`, `degree_name` and `count_name`, sorted by type and degree.
+///
+fn degree_histogram_df(
+    elements: impl IntoIterator<Item = (String, String)>,
+    relations: impl IntoIterator<Item = (String, String)>,
+    [group_name, degree_name, count_name]: [&str; 3],
+) -> PolarsResult<DataFrame> {
+    let mut related: HashMap<String, HashSet<String>> = HashMap::new();
+    for (element, item) in relations {
+        related.entry(element).or_default().insert(item);
+    }
+    let mut counts: BTreeMap<(String, usize), u64> = BTreeMap::new();
+    for (element, element_type) in elements {
+        let degree = related.get(&element).map(HashSet::len).unwrap_or_default();
+        *counts.entry((element_type, degree)).or_default() += 1;
+    }
+    DataFrame::new(vec![
+        Column::new(
+            group_name.into(),
+            counts.keys().map(|(t, _)| t.as_str()).collect::<Vec<_>>(),
+        ),
+        Column::new(
+            degree_name.into(),
+            counts.keys().map(|(_, d)| *d as u64).collect::<Vec<_>>(),
+        ),
+        Column::new(count_name.into(), counts.values().copied().collect::<Vec<_>>()),
+    ])
+}
+
+fn column_pairs(df: &DataFrame, a: &str, b: &str) -> PolarsResult<Vec<(String, String)>> {
+    Ok(column_strings(df, a)?
+        .into_iter()
+        .zip(column_strings(df, b)?)
+        .collect())
+}
+
+///
+/// Summarize the DataFrames of an OCEL
+///
+/// Returns the [DataFrame]s
+/// * `overview`: single row with the number of `events`, `objects`, `event_types`, `object_types`, `e2o` and `o2o` relations,
+/// * `event_types`: `ocel:activity` and number of `events`,
+/// * `object_types`: `ocel:type` and number of `objects`,
+/// * `e2o_degrees`: `ocel:type`, `events` (number of distinct related events) and number of `objects`,
+/// * `o2o_degrees`: `ocel:type`, `related_objects` (number of distinct target objects) and number of `objects`, and
+/// * `objects_per_event`: `ocel:activity`, `objects` (number of distinct related objects) and number of `events`
+///
+pub fn ocel_summary(ocel: &OCEL2DataFrames) -> PolarsResult<Vec<(&'static str, DataFrame)>> {
+    let event_types = value_counts_df(&ocel.events, OCEL_EVENT_TYPE_KEY, "events")?;
+    let object_types = value_counts_df(&ocel.objects, OCEL_OBJECT_TYPE_KEY, "objects")?;
+    let overview = DataFrame::new(vec![
+        Column::new("events".into(), [ocel.events.height() as u64]),
+        Column::new("objects".into(), [ocel.objects.height() as u64]),
+        Column::new("event_types".into(), [event_types.height() as u64]),
+        Column::new("object_types".into(), [object_types.height() as u64]),
+        Column::new("e2o".into(), [ocel.e2o.height() as u64]),
+        Column::new("o2o".into(), [ocel.o2o.height() as u64]),
+    ])?;
+    let objects = column_pairs(&ocel.objects, OCEL_OBJECT_ID_KEY, OCEL_OBJECT_TYPE_KEY)?;
+    let events = column_pairs(&ocel.events, OCEL_EVENT_ID_KEY, OCEL_EVENT_TYPE_KEY)?;
+    let e2o = column_pairs(&ocel.e2o, OCEL_EVENT_ID_KEY, OCEL_OBJECT_ID_KEY)?;
+    let e2o_degrees = degree_histogram_df(
+        objects.clone(),
+        e2o.iter().map(|(e, o)| (o.clone(), e.clone())),
+        [OCEL_OBJECT_TYPE_KEY, "events", "objects"],
+    )?;
+    let o2o_degrees = degree_histogram_df(
+        objects,
+        column_pairs(&ocel.o2o, OCEL_OBJECT_ID_KEY, OCEL_OBJECT_ID_2_KEY)?,
+        [OCEL_OBJECT_TYPE_KEY, "related_objects", "objects"],
+    )?;
+    let objects_per_event =
+        degree_histogram_df(events, e2o, [OCEL_EVENT_TYPE_KEY, "objects", "events"])?;
+    Ok(vec![
+        ("overview", overview),
+        ("event_types", event_types),
+        ("object_types", object_types),
+        ("e2o_degrees", e2o_degrees),
+        ("o2o_degrees", o2o_degrees),
+        ("objects_per_event", objects_per_event),
+    ])
+}
+
+///
+/// Summarize an OCEL
+///
+/// Returns a dict with the DataFrames
+/// * `overview` (`events`, `objects`, `event_types`, `object_types`, `e2o`, `o2o`),
+/// * `event_types` (`ocel:activity`, `events`),
+/// * `object_types` (`ocel:type`, `objects`),
+/// * `e2o_degrees` (`ocel:type`, `events`, `objects`): number of objects of each type related to the given number of events,
+/// * `o2o_degrees` (`ocel:type`, `related_objects`, `objects`): number of objects of each type related to the given number of other objects, and
+/// * `objects_per_event` (`ocel:activity`, `objects`, `events`): number of events of each type related to the given number of objects
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+///
+#[pyfunction]
+pub fn ocel_summary_rs(
+    ocel: HashMap<String, PyDataFrame>,
+) -> PyResult<HashMap<String, PyDataFrame>> {
+    let ocel_dfs = ocel_dfs_from_py(ocel)?;
+    let summary = ocel_summary(&ocel_dfs)
+        .map_err(|e| PyValueError::new_err(format!("Failed to summarize OCEL: {e:?}")))?;
+    Ok(summary
+        .into_iter()
+        .map(|(name, df)| (name.to_string(), PyDataFrame(df)))
+        .collect())
+}
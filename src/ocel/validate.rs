@@ -0,0 +1,364 @@
+use std::collections::{HashMap, HashSet};
+
+use process_mining::{
+    ocel::ocel_struct::{OCELAttributeValue, OCELType, OCELTypeAttribute},
+    OCEL,
+};
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use pyo3_polars::PyDataFrame;
+use serde::Serialize;
+
+use super::{df_to_ocel::ocel_attribute_type_name, import_ocel_from_path, ocel_from_py};
+
+/// Reference (E2O or O2O relationship) to an object id that does not exist
+#[derive(Debug, Clone, Serialize)]
+pub struct DanglingReference {
+    /// Id of the referencing event (E2O) or object (O2O)
+    pub source_id: String,
+    /// The unknown object id
+    pub object_id: String,
+    pub qualifier: String,
+}
+
+/// Attribute value of an event/object that is not declared on its type
+#[derive(Debug, Clone, Serialize)]
+pub struct UndeclaredAttribute {
+    /// Id of the event/object
+    pub id: String,
+    /// Event/object type
+    pub type_name: String,
+    pub attribute: String,
+}
+
+/// Attribute value whose type does not match the declared attribute type
+#[derive(Debug, Clone, Serialize)]
+pub struct AttributeTypeMismatch {
+    /// Id of the event/object
+    pub id: String,
+    /// Event/object type
+    pub type_name: String,
+    pub attribute: String,
+    /// Declared attribute type
+    pub expected: String,
+    /// Type of the actual value
+    pub actual: String,
+}
+
+/// Referential integrity and schema issues found in an [OCEL]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OCELValidationReport {
+    /// E2O relationships referencing unknown object ids
+    pub dangling_e2o: Vec<DanglingReference>,
+    /// O2O relationships referencing unknown object ids
+    pub dangling_o2o: Vec<DanglingReference>,
+    /// Event ids occurring more than once
+    pub duplicate_event_ids: Vec<String>,
+    /// Object ids occurring more than once
+    pub duplicate_object_ids: Vec<String>,
+    /// Ids of events without any E2O relationship
+    pub events_without_objects: Vec<String>,
+    /// Events with an event type that is not declared
+    pub undeclared_event_types: Vec<String>,
+    /// Objects with an object type that is not declared
+    pub undeclared_object_types: Vec<String>,
+    /// Event attribute values not declared on their event type
+    pub undeclared_event_attributes: Vec<UndeclaredAttribute>,
+    /// Object attribute values not declared on their object type
+    pub undeclared_object_attributes: Vec<UndeclaredAttribute>,
+    /// Event/object attribute values not matching their declared type
+    pub attribute_type_mismatches: Vec<AttributeTypeMismatch>,
+}
+
+impl OCELValidationReport {
+    /// Total number of issues found
+    pub fn num_issues(&self) -> usize {
+        self.dangling_e2o.len()
+            + self.dangling_o2o.len()
+            + self.duplicate_event_ids.len()
+            + self.duplicate_object_ids.len()
+            + self.events_without_objects.len()
+            + self.undeclared_event_types.len()
+            + self.undeclared_object_types.len()
+            + self.undeclared_event_attributes.len()
+            + self.undeclared_object_attributes.len()
+            + self.attribute_type_mismatches.len()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.num_issues() == 0
+    }
+
+    /// Short human-readable summary of the number of issues per category
+    pub fn summary(&self) -> String {
+        [
+            ("dangling E2O references", self.dangling_e2o.len()),
+            ("dangling O2O references", self.dangling_o2o.len()),
+            ("duplicate event ids", self.duplicate_event_ids.len()),
+            ("duplicate object ids", self.duplicate_object_ids.len()),
+            ("events without objects", self.events_without_objects.len()),
+            ("undeclared event types", self.undeclared_event_types.len()),
+            ("undeclared object types", self.undeclared_object_types.len()),
+            (
+                "undeclared event attributes",
+                self.undeclared_event_attributes.len(),
+            ),
+            (
+                "undeclared object attributes",
+                self.undeclared_object_attributes.len(),
+            ),
+            (
+                "attribute type mismatches",
+                self.attribute_type_mismatches.len(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, n)| *n > 0)
+        .map(|(name, n)| format!("{n} {name}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+/// Ids occurring more than once (in order of their first repeated occurrence)
+fn duplicate_ids<'a>(ids: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut duplicates = Vec::new();
+    let mut reported = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) && reported.insert(id) {
+            duplicates.push(id.clone());
+        }
+    }
+    duplicates
+}
+
+/// Check whether a value is compatible with the declared attribute type
+fn value_matches_type(value: &OCELAttributeValue, declared: &str) -> bool {
+    match ocel_attribute_type_name(value) {
+        // Null values are compatible with every type
+        None => true,
+        Some(actual) => {
+            actual.eq_ignore_ascii_case(declared)
+                || (actual == "integer" && declared.eq_ignore_ascii_case("float"))
+        }
+    }
+}
+
+/// Check the attribute values of an event/object against the attributes declared on its type
+fn check_attributes<'a>(
+    id: &str,
+    type_name: &str,
+    declared: Option<&HashMap<&str, &str>>,
+    attributes: impl Iterator<Item = (&'a String, &'a OCELAttributeValue)>,
+    undeclared: &mut Vec<UndeclaredAttribute>,
+    mismatches: &mut Vec<AttributeTypeMismatch>,
+) {
+    // Attributes of undeclared types are already covered by the undeclared type check
+    let Some(declared) = declared else {
+        return;
+    };
+    for (name, value) in attributes {
+        match declared.get(name.as_str()) {
+            None => undeclared.push(UndeclaredAttribute {
+                id: id.to_string(),
+                type_name: type_name.to_string(),
+                attribute: name.clone(),
+            }),
+            Some(expected) if !value_matches_type(value, expected) => {
+                mismatches.push(AttributeTypeMismatch {
+                    id: id.to_string(),
+                    type_name: type_name.to_string(),
+                    attribute: name.clone(),
+                    expected: expected.to_string(),
+                    actual: ocel_attribute_type_name(value)
+                        .unwrap_or_default()
+                        .to_string(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+///
+/// Check an [OCEL] for referential integrity and schema issues
+///
+/// Detects dangling E2O/O2O references, duplicate event/object ids, events without objects,
+/// undeclared event/object types, undeclared attributes and attribute type mismatches.
+///
+pub fn validate_ocel(ocel: &OCEL) -> OCELValidationReport {
+    let mut report = OCELValidationReport::default();
+    let object_ids: HashSet<&String> = ocel.objects.iter().map(|o| &o.id).collect();
+    let declared_event_types: HashMap<&str, HashMap<&str, &str>> = ocel
+        .event_types
+        .iter()
+        .map(|t| {
+            (
+                t.name.as_str(),
+                t.attributes
+                    .iter()
+                    .map(|a| (a.name.as_str(), a.value_type.as_str()))
+                    .collect(),
+            )
+        })
+        .collect();
+    let declared_object_types: HashMap<&str, HashMap<&str, &str>> = ocel
+        .object_types
+        .iter()
+        .map(|t| {
+            (
+                t.name.as_str(),
+                t.attributes
+                    .iter()
+                    .map(|a| (a.name.as_str(), a.value_type.as_str()))
+                    .collect(),
+            )
+        })
+        .collect();
+
+    report.duplicate_event_ids = duplicate_ids(ocel.events.iter().map(|e| &e.id));
+    report.duplicate_object_ids = duplicate_ids(ocel.objects.iter().map(|o| &o.id));
+
+    let mut undeclared_event_types = HashSet::new();
+    for e in &ocel.events {
+        if e.relationships.is_empty() {
+            report.events_without_objects.push(e.id.clone());
+        }
+        for r in &e.relationships {
+            if !object_ids.contains(&r.object_id) {
+                report.dangling_e2o.push(DanglingReference {
+                    source_id: e.id.clone(),
+                    object_id: r.object_id.clone(),
+                    qualifier: r.qualifier.clone(),
+                });
+            }
+        }
+        let declared = declared_event_types.get(e.event_type.as_str());
+        if declared.is_none() && undeclared_event_types.insert(&e.event_type) {
+            report.undeclared_event_types.push(e.event_type.clone());
+        }
+        check_attributes(
+            &e.id,
+            &e.event_type,
+            declared,
+            e.attributes.iter().map(|a| (&a.name, &a.value)),
+            &mut report.undeclared_event_attributes,
+            &mut report.attribute_type_mismatches,
+        );
+    }
+
+    let mut undeclared_object_types = HashSet::new();
+    for o in &ocel.objects {
+        for r in &o.relationships {
+            if !object_ids.contains(&r.object_id) {
+                report.dangling_o2o.push(DanglingReference {
+                    source_id: o.id.clone(),
+                    object_id: r.object_id.clone(),
+                    qualifier: r.qualifier.clone(),
+                });
+            }
+        }
+        let declared = declared_object_types.get(o.object_type.as_str());
+        if declared.is_none() && undeclared_object_types.insert(&o.object_type) {
+            report.undeclared_object_types.push(o.object_type.clone());
+        }
+        check_attributes(
+            &o.id,
+            &o.object_type,
+            declared,
+            o.attributes.iter().map(|a| (&a.name, &a.value)),
+            &mut report.undeclared_object_attributes,
+            &mut report.attribute_type_mismatches,
+        );
+    }
+    report
+}
+
+///
+/// Validate an OCEL2 file (.xml or .json)
+///
+/// Returns a json-encoding of the validation report
+///
+/// * `path` - The filepath of the OCEL2 file to validate
+///
+#[pyfunction]
+pub fn validate_ocel_path_rs(path: String) -> PyResult<String> {
+    let ocel = import_ocel_from_path(&path)?;
+    Ok(serde_json::to_string(&validate_ocel(&ocel)).unwrap())
+}
+
+/// Type declarations by type name, mapping attribute names to attribute types (e.g., `"float"`)
+pub type DeclaredTypes = HashMap<String, HashMap<String, String>>;
+
+/// Convert [DeclaredTypes] to [OCELType]s (sorted by type and attribute name)
+pub fn declared_ocel_types(types: &DeclaredTypes) -> Vec<OCELType> {
+    let mut ocel_types: Vec<OCELType> = types
+        .iter()
+        .map(|(name, attributes)| {
+            let mut attributes: Vec<OCELTypeAttribute> = attributes
+                .iter()
+                .map(|(name, value_type)| OCELTypeAttribute {
+                    name: name.clone(),
+                    value_type: value_type.clone(),
+                })
+                .collect();
+            attributes.sort_by(|a, b| a.name.cmp(&b.name));
+            OCELType {
+                name: name.clone(),
+                attributes,
+            }
+        })
+        .collect();
+    ocel_types.sort_by(|a, b| a.name.cmp(&b.name));
+    ocel_types
+}
+
+/// Replace the type declarations of an [OCEL] (e.g., derived from DataFrames) by the given declared types
+pub fn apply_declared_types(
+    ocel: &mut OCEL,
+    event_types: Option<&DeclaredTypes>,
+    object_types: Option<&DeclaredTypes>,
+) {
+    if let Some(event_types) = event_types {
+        ocel.event_types = declared_ocel_types(event_types);
+    }
+    if let Some(object_types) = object_types {
+        ocel.object_types = declared_ocel_types(object_types);
+    }
+}
+
+///
+/// Validate an OCEL given as dict of DataFrames
+///
+/// Returns a json-encoding of the validation report.
+/// Note, that without declared types, the type declarations are derived from the DataFrames themselves,
+/// so no undeclared types, undeclared attributes or type mismatches can be reported for the respective (event/object) types.
+///
+/// * `ocel` - The OCEL as dict of DataFrames (as returned by the OCEL import functions)
+/// * `event_types` - Optional declared event types (event type → attribute name → attribute type, e.g., `"string"`, `"time"`, `"integer"`, `"float"` or `"boolean"`)
+/// * `object_types` - Optional declared object types (object type → attribute name → attribute type)
+///
+#[pyfunction]
+#[pyo3(signature = (ocel, event_types=None, object_types=None))]
+pub fn validate_ocel_dfs_rs(
+    ocel: HashMap<String, PyDataFrame>,
+    event_types: Option<DeclaredTypes>,
+    object_types: Option<DeclaredTypes>,
+) -> PyResult<String> {
+    let mut ocel = ocel_from_py(ocel)?;
+    apply_declared_types(&mut ocel, event_types.as_ref(), object_types.as_ref());
+    Ok(serde_json::to_string(&validate_ocel(&ocel)).unwrap())
+}
+
+/// Fail with a [PyValueError] if the [OCEL] has referential integrity or schema issues
+pub fn ensure_valid_ocel(ocel: &OCEL) -> PyResult<()> {
+    let report = validate_ocel(ocel);
+    if report.is_valid() {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Invalid OCEL: {}",
+            report.summary()
+        )))
+    }
+}
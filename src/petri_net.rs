@@ -0,0 +1,306 @@
+//! JSON encoding of Petri nets passed between Rust and Python
+//!
+//! A Petri net is encoded as JSON object with the keys
+//! * `places`: array of objects `{"id": ...}`,
+//! * `transitions`: array of objects `{"id": ..., "label": ...}` (with a `null` label for silent transitions),
+//! * `arcs`: array of objects `{"source": ..., "target": ..., "weight": ...}` (from a place to a transition or vice versa),
+//! * `initial_marking`: object mapping place ids to their number of tokens (or `null`), and
+//! * `final_markings`: array of markings (or `null`).
+//!
+//! Place and transition ids are UUIDs when encoding. When decoding, other (unique) id strings are mapped to new UUIDs.
+use std::collections::HashMap;
+
+use process_mining::{
+    petri_net::petri_net_struct::{ArcType, Marking, PlaceID, TransitionID},
+    PetriNet,
+};
+use pyo3::{exceptions::PyValueError, PyResult};
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+
+fn marking_to_json(marking: &Marking) -> Value {
+    Value::Object(
+        marking
+            .iter()
+            .map(|(place, tokens)| (place.get_uuid().to_string(), json!(tokens)))
+            .collect::<Map<_, _>>(),
+    )
+}
+
+///
+/// Encode a [PetriNet] as JSON (see module documentation for the layout)
+///
+pub fn petri_net_to_json(net: &PetriNet) -> Value {
+    let mut places: Vec<&Uuid> = net.places.keys().collect();
+    places.sort();
+    let mut transitions: Vec<_> = net.transitions.values().collect();
+    transitions.sort_by(|a, b| (&a.label, a.id).cmp(&(&b.label, b.id)));
+    json!({
+        "places": places.iter().map(|p| json!({"id": p.to_string()})).collect::<Vec<_>>(),
+        "transitions": transitions
+            .iter()
+            .map(|t| json!({"id": t.id.to_string(), "label": t.label}))
+            .collect::<Vec<_>>(),
+        "arcs": net.arcs.iter().map(|a| {
+            let (source, target) = match a.from_to {
+                ArcType::PlaceTransition(p, t) => (p, t),
+                ArcType::TransitionPlace(t, p) => (t, p),
+            };
+            json!({"source": source.to_string(), "target": target.to_string(), "weight": a.weight})
+        }).collect::<Vec<_>>(),
+        "initial_marking": net.initial_marking.as_ref().map(marking_to_json),
+        "final_markings": net
+            .final_markings
+            .as_ref()
+            .map(|markings| markings.iter().map(marking_to_json).collect::<Vec<_>>()),
+    })
+}
+
+/// Maps the id strings of an encoded Petri net to UUIDs
+#[derive(Default)]
+struct NodeIds {
+    places: HashMap<String, PlaceID>,
+    transitions: HashMap<String, TransitionID>,
+}
+
+fn node_uuid(id: &str) -> Uuid {
+    Uuid::parse_str(id).unwrap_or_else(|_| Uuid::new_v4())
+}
+
+fn id_field<'a>(value: &'a Value, key: &str) -> Result<&'a str, String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("Missing '{key}' in {value}"))
+}
+
+fn json_array<'a>(value: &'a Value, key: &str) -> Result<&'a [Value], String> {
+    match value.get(key) {
+        Some(Value::Array(a)) => Ok(a),
+        Some(Value::Null) | None => Ok(&[]),
+        Some(v) => Err(format!("Expected array for '{key}', got {v}")),
+    }
+}
+
+fn marking_from_json(value: &Value, ids: &NodeIds) -> Result<Marking, String> {
+    value
+        .as_object()
+        .ok_or_else(|| format!("Expected marking object, got {value}"))?
+        .iter()
+        .map(|(place, tokens)| {
+            let tokens = tokens
+                .as_u64()
+                .ok_or_else(|| format!("Invalid number of tokens {tokens} for place '{place}'"))?;
+            let place = ids
+                .places
+                .get(place)
+                .ok_or_else(|| format!("Unknown place '{place}' in marking"))?;
+            Ok((*place, tokens))
+        })
+        .collect()
+}
+
+///
+/// Decode a [PetriNet] from JSON (see module documentation for the layout)
+///
+pub fn petri_net_from_json(value: &Value) -> Result<PetriNet, String> {
+    let mut net = PetriNet::new();
+    let mut ids = NodeIds::default();
+    for place in json_array(value, "places")? {
+        let id = id_field(place, "id")?;
+        if ids.places.contains_key(id) {
+            return Err(format!("Duplicate place id '{id}'"));
+        }
+        let place_id = net.add_place(Some(node_uuid(id)));
+        ids.places.insert(id.to_string(), place_id);
+    }
+    for transition in json_array(value, "transitions")? {
+        let id = id_field(transition, "id")?;
+        if ids.transitions.contains_key(id) || ids.places.contains_key(id) {
+            return Err(format!("Duplicate transition id '{id}'"));
+        }
+        let label = match transition.get("label") {
+            Some(Value::String(label)) => Some(label.clone()),
+            Some(Value::Null) | None => None,
+            Some(label) => return Err(format!("Invalid transition label {label}")),
+        };
+        let transition_id = net.add_transition(label, Some(node_uuid(id)));
+        ids.transitions.insert(id.to_string(), transition_id);
+    }
+    for arc in json_array(value, "arcs")? {
+        let source = id_field(arc, "source")?;
+        let target = id_field(arc, "target")?;
+        let from_to = match (
+            ids.places.get(source),
+            ids.transitions.get(source),
+            ids.places.get(target),
+            ids.transitions.get(target),
+        ) {
+            (Some(p), _, _, Some(t)) => ArcType::place_to_transition(*p, *t),
+            (_, Some(t), Some(p), _) => ArcType::transition_to_place(*t, *p),
+            _ => {
+                return Err(format!(
+                    "Invalid arc from '{source}' to '{target}' (expected an arc between a place and a transition)"
+                ))
+            }
+        };
+        let weight = match arc.get("weight") {
+            Some(Value::Null) | None => None,
+            Some(w) => Some(
+                w.as_u64()
+                    .and_then(|w| u32::try_from(w).ok())
+                    .ok_or_else(|| format!("Invalid arc weight {w}"))?,
+            ),
+        };
+        net.add_arc(from_to, weight);
+    }
+    net.initial_marking = match value.get("initial_marking") {
+        Some(Value::Null) | None => None,
+        Some(m) => Some(marking_from_json(m, &ids)?),
+    };
+    net.final_markings = match value.get("final_markings") {
+        Some(Value::Null) | None => None,
+        Some(_) => Some(
+            json_array(value, "final_markings")?
+                .iter()
+                .map(|m| marking_from_json(m, &ids))
+                .collect::<Result<_, String>>()?,
+        ),
+    };
+    Ok(net)
+}
+
+/// Transition of an [IndexedPetriNet] with its input and output places (and arc weights)
+#[derive(Debug, Clone)]
+pub struct IndexedTransition {
+    /// Label of the transition (`None` for silent transitions)
+    pub label: Option<String>,
+    pub inputs: Vec<(usize, u64)>,
+    pub outputs: Vec<(usize, u64)>,
+}
+
+///
+/// [PetriNet] with places and transitions indexed by consecutive ids and markings as token counts per place id
+///
+/// Places are ordered by id, transitions by label and id.
+///
+#[derive(Debug, Clone)]
+pub struct IndexedPetriNet {
+    pub num_places: usize,
+    pub transitions: Vec<IndexedTransition>,
+    pub initial_marking: Vec<u64>,
+    /// First final marking of the net (no tokens if the net has no final marking)
+    pub final_marking: Vec<u64>,
+}
+
+impl IndexedPetriNet {
+    /// Index a [PetriNet] (which needs an initial marking)
+    pub fn new(net: &PetriNet) -> Result<Self, String> {
+        let mut places: Vec<&Uuid> = net.places.keys().collect();
+        places.sort();
+        let place_index: HashMap<Uuid, usize> =
+            places.iter().enumerate().map(|(i, p)| (**p, i)).collect();
+        let mut transitions: Vec<_> = net.transitions.values().collect();
+        transitions.sort_by(|a, b| (&a.label, a.id).cmp(&(&b.label, b.id)));
+        let transition_index: HashMap<Uuid, usize> =
+            transitions.iter().enumerate().map(|(i, t)| (t.id, i)).collect();
+        let mut indexed: Vec<IndexedTransition> = transitions
+            .iter()
+            .map(|t| IndexedTransition {
+                label: t.label.clone(),
+                inputs: Vec::new(),
+                outputs: Vec::new(),
+            })
+            .collect();
+        let add_weight = |arcs: &mut Vec<(usize, u64)>, place: usize, weight: u64| {
+            match arcs.iter_mut().find(|(p, _)| *p == place) {
+                Some((_, w)) => *w += weight,
+                None => arcs.push((place, weight)),
+            }
+        };
+        for arc in &net.arcs {
+            let (place, transition, input) = match arc.from_to {
+                ArcType::PlaceTransition(p, t) => (p, t, true),
+                ArcType::TransitionPlace(t, p) => (p, t, false),
+            };
+            let (Some(place), Some(transition)) =
+                (place_index.get(&place), transition_index.get(&transition))
+            else {
+                return Err(format!("Arc between unknown place {place} and transition {transition}"));
+            };
+            let transition = &mut indexed[*transition];
+            let arcs = if input {
+                &mut transition.inputs
+            } else {
+                &mut transition.outputs
+            };
+            add_weight(arcs, *place, arc.weight as u64);
+        }
+        let to_vec = |marking: &Marking| -> Result<Vec<u64>, String> {
+            let mut tokens = vec![0; places.len()];
+            for (place, count) in marking {
+                let place = place_index
+                    .get(&place.get_uuid())
+                    .ok_or_else(|| format!("Unknown place {} in marking", place.get_uuid()))?;
+                tokens[*place] += count;
+            }
+            Ok(tokens)
+        };
+        let initial_marking = to_vec(
+            net.initial_marking
+                .as_ref()
+                .ok_or_else(|| "Petri net has no initial marking".to_string())?,
+        )?;
+        let final_marking = match net.final_markings.as_ref().and_then(|m| m.first()) {
+            Some(marking) => to_vec(marking)?,
+            None => vec![0; places.len()],
+        };
+        Ok(Self {
+            num_places: places.len(),
+            transitions: indexed,
+            initial_marking,
+            final_marking,
+        })
+    }
+
+    /// Check if a transition is enabled in a marking
+    pub fn is_enabled(&self, transition: usize, marking: &[u64]) -> bool {
+        self.transitions[transition]
+            .inputs
+            .iter()
+            .all(|(p, w)| marking[*p] >= *w)
+    }
+
+    /// Number of tokens missing in a marking to enable a transition
+    pub fn missing_tokens(&self, transition: usize, marking: &[u64]) -> u64 {
+        self.transitions[transition]
+            .inputs
+            .iter()
+            .map(|(p, w)| w.saturating_sub(marking[*p]))
+            .sum()
+    }
+
+    /// Fire an enabled transition
+    pub fn fire(&self, transition: usize, marking: &mut [u64]) {
+        let transition = &self.transitions[transition];
+        for (p, w) in &transition.inputs {
+            marking[*p] -= w;
+        }
+        for (p, w) in &transition.outputs {
+            marking[*p] += w;
+        }
+    }
+}
+
+/// JSON-encoding of a [PetriNet] returned to Python
+pub fn petri_net_to_py(net: &PetriNet) -> String {
+    petri_net_to_json(net).to_string()
+}
+
+/// Decode the JSON-encoding of a Petri net passed from Python
+pub fn petri_net_from_py(net: &str) -> PyResult<PetriNet> {
+    let value: Value = serde_json::from_str(net)
+        .map_err(|e| PyValueError::new_err(format!("Invalid Petri net JSON: {e}")))?;
+    petri_net_from_json(&value)
+        .map_err(|e| PyValueError::new_err(format!("Invalid Petri net: {e}")))
+}
@@ -0,0 +1,384 @@
+//! PNML import and export of Petri nets
+//!
+//! Nets are read into (and written from) the JSON layout of [crate::petri_net], so PNML ids that are UUIDs
+//! (as written by [export_pnml_file]) are kept, while other ids are replaced by new UUIDs.
+//! Silent transitions are marked with the ProM/pm4py convention (`<toolspecific tool="ProM" activity="$invisible$"/>`),
+//! final markings are stored in a `finalmarkings` element of the net.
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+};
+
+use process_mining::PetriNet;
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use quick_xml::{
+    events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event as XMLEvent},
+    Writer,
+};
+use serde_json::{json, Map, Value};
+
+use crate::petri_net::{petri_net_from_json, petri_net_from_py, petri_net_to_json, petri_net_to_py};
+
+const INVISIBLE_ACTIVITY: &str = "$invisible$";
+
+/// Value of an attribute of a PNML element
+fn xml_attr(e: &BytesStart, key: &[u8]) -> Result<Option<String>, String> {
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| format!("Invalid XML attribute: {e}"))?;
+        if attr.key.as_ref() == key {
+            return attr
+                .unescape_value()
+                .map(|v| Some(v.to_string()))
+                .map_err(|e| format!("Invalid XML attribute value: {e}"));
+        }
+    }
+    Ok(None)
+}
+
+fn required_attr(e: &BytesStart, key: &[u8]) -> Result<String, String> {
+    xml_attr(e, key)?.ok_or_else(|| {
+        format!(
+            "Missing attribute '{}' of element '{}'",
+            String::from_utf8_lossy(key),
+            String::from_utf8_lossy(e.name().as_ref())
+        )
+    })
+}
+
+fn parse_number(text: &str, what: &str) -> Result<u64, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("Invalid {what} '{}'", text.trim()))
+}
+
+/// State of the PNML parser
+#[derive(Default)]
+struct PNMLParser {
+    /// Names of the currently open elements
+    stack: Vec<Vec<u8>>,
+    text: String,
+    places: Vec<Value>,
+    transitions: Vec<Value>,
+    arcs: Vec<Value>,
+    initial_marking: Map<String, Value>,
+    final_markings: Option<Vec<Map<String, Value>>>,
+    /// Place referenced by the currently read final marking entry
+    marking_place: Option<String>,
+    /// Only the first net of a PNML file is read
+    nets: usize,
+}
+
+impl PNMLParser {
+    fn parent(&self) -> Option<&[u8]> {
+        self.stack.iter().rev().nth(1).map(Vec::as_slice)
+    }
+
+    fn in_first_net(&self) -> bool {
+        self.nets == 1
+    }
+
+    fn start_element(&mut self, e: &BytesStart) -> Result<(), String> {
+        self.stack.push(e.name().as_ref().to_vec());
+        self.text.clear();
+        if e.name().as_ref() == b"net" {
+            self.nets += 1;
+        }
+        if !self.in_first_net() {
+            return Ok(());
+        }
+        match (e.name().as_ref(), self.parent()) {
+            (b"place", Some(b"marking")) => self.marking_place = Some(required_attr(e, b"idref")?),
+            (b"place", _) => self.places.push(json!({ "id": required_attr(e, b"id")? })),
+            (b"transition", _) => self
+                .transitions
+                .push(json!({ "id": required_attr(e, b"id")?, "label": null })),
+            (b"arc", _) => self.arcs.push(json!({
+                "source": required_attr(e, b"source")?,
+                "target": required_attr(e, b"target")?,
+                "weight": 1,
+            })),
+            (b"toolspecific", Some(b"transition")) => {
+                if xml_attr(e, b"activity")?.as_deref() == Some(INVISIBLE_ACTIVITY) {
+                    if let Some(transition) = self.transitions.last_mut() {
+                        transition["invisible"] = json!(true);
+                    }
+                }
+            }
+            (b"marking", Some(b"finalmarkings")) => {
+                self.final_markings.get_or_insert_with(Vec::new).push(Map::new())
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn end_element(&mut self) -> Result<(), String> {
+        if self.in_first_net() && self.stack.last().map(Vec::as_slice) == Some(b"text") {
+            let text = std::mem::take(&mut self.text);
+            let context: Vec<&[u8]> = self.stack.iter().rev().skip(1).take(2).map(Vec::as_slice).collect();
+            match context.as_slice() {
+                [b"name", b"transition"] => {
+                    if let Some(transition) = self.transitions.last_mut() {
+                        transition["label"] = json!(text);
+                    }
+                }
+                [b"initialMarking", b"place"] => {
+                    let tokens = parse_number(&text, "initial marking")?;
+                    if let Some(id) = self.places.last().and_then(|p| p["id"].as_str()) {
+                        if tokens > 0 {
+                            self.initial_marking.insert(id.to_string(), json!(tokens));
+                        }
+                    }
+                }
+                [b"inscription", b"arc"] => {
+                    let weight = parse_number(&text, "arc weight")?;
+                    if let Some(arc) = self.arcs.last_mut() {
+                        arc["weight"] = json!(weight);
+                    }
+                }
+                [b"place", b"marking"] => {
+                    let tokens = parse_number(&text, "final marking")?;
+                    if let (Some(place), Some(marking)) = (
+                        self.marking_place.take(),
+                        self.final_markings.as_mut().and_then(|m| m.last_mut()),
+                    ) {
+                        if tokens > 0 {
+                            marking.insert(place, json!(tokens));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.stack.pop();
+        Ok(())
+    }
+
+    fn into_json(self) -> Value {
+        let transitions: Vec<Value> = self
+            .transitions
+            .into_iter()
+            .map(|mut t| {
+                if t.get("invisible").is_some() {
+                    t["label"] = Value::Null;
+                }
+                json!({ "id": t["id"], "label": t["label"] })
+            })
+            .collect();
+        json!({
+            "places": self.places,
+            "transitions": transitions,
+            "arcs": self.arcs,
+            "initial_marking": self.initial_marking,
+            "final_markings": self.final_markings,
+        })
+    }
+}
+
+///
+/// Parse a PNML Petri net (the first net of the file)
+///
+/// Transitions without name or marked as invisible are silent. Arc weights are read from the `inscription`, markings from
+/// the `initialMarking` of places and the `finalmarkings` of the net.
+///
+pub fn parse_pnml<R: BufRead>(reader: R) -> Result<PetriNet, String> {
+    let mut reader = quick_xml::Reader::from_reader(reader);
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+    let mut parser = PNMLParser::default();
+    loop {
+        match reader
+            .read_event_into(&mut buf)
+            .map_err(|e| format!("Invalid XML: {e}"))?
+        {
+            XMLEvent::Start(e) => parser.start_element(&e)?,
+            XMLEvent::Empty(e) => {
+                parser.start_element(&e)?;
+                parser.end_element()?;
+            }
+            XMLEvent::Text(t) => parser.text.push_str(
+                &t.unescape()
+                    .map_err(|e| format!("Invalid XML text: {e}"))?,
+            ),
+            XMLEvent::CData(t) => parser.text.push_str(&String::from_utf8_lossy(&t)),
+            XMLEvent::End(_) => parser.end_element()?,
+            XMLEvent::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    if parser.nets == 0 {
+        return Err("No net found in PNML".to_string());
+    }
+    petri_net_from_json(&parser.into_json())
+}
+
+///
+/// Import a PNML file
+///
+pub fn import_pnml_file(path: &str) -> Result<PetriNet, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {path}: {e}"))?;
+    parse_pnml(BufReader::new(file))
+}
+
+/// Writes PNML elements (mapping XML errors to strings)
+struct PNMLWriter<W: Write> {
+    writer: Writer<W>,
+}
+
+impl<W: Write> PNMLWriter<W> {
+    fn event(&mut self, event: XMLEvent) -> Result<(), String> {
+        self.writer
+            .write_event(event)
+            .map_err(|e| format!("Failed to write PNML: {e}"))
+    }
+
+    fn start(&mut self, name: &str, attributes: &[(&str, &str)]) -> Result<(), String> {
+        let mut start = BytesStart::new(name);
+        start.extend_attributes(attributes.iter().copied());
+        self.event(XMLEvent::Start(start))
+    }
+
+    fn empty(&mut self, name: &str, attributes: &[(&str, &str)]) -> Result<(), String> {
+        let mut start = BytesStart::new(name);
+        start.extend_attributes(attributes.iter().copied());
+        self.event(XMLEvent::Empty(start))
+    }
+
+    fn end(&mut self, name: &str) -> Result<(), String> {
+        self.event(XMLEvent::End(BytesEnd::new(name)))
+    }
+
+    /// Write `<name><text>text</text></name>`
+    fn text_element(&mut self, name: &str, text: &str) -> Result<(), String> {
+        self.start(name, &[])?;
+        self.start("text", &[])?;
+        self.event(XMLEvent::Text(BytesText::new(text)))?;
+        self.end("text")?;
+        self.end(name)
+    }
+}
+
+fn json_str(value: &Value) -> &str {
+    value.as_str().unwrap_or_default()
+}
+
+///
+/// Write a [PetriNet] as PNML (with UUIDs as ids)
+///
+pub fn write_pnml<W: Write>(net: &PetriNet, writer: W) -> Result<(), String> {
+    let net = petri_net_to_json(net);
+    let mut w = PNMLWriter {
+        writer: Writer::new_with_indent(writer, b' ', 2),
+    };
+    w.event(XMLEvent::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    w.start("pnml", &[])?;
+    w.start(
+        "net",
+        &[
+            ("id", "net1"),
+            ("type", "http://www.pnml.org/version-2009/grammar/pnmlcoremodel"),
+        ],
+    )?;
+    w.text_element("name", "net1")?;
+    w.start("page", &[("id", "n0")])?;
+    for place in net["places"].as_array().into_iter().flatten() {
+        let id = json_str(&place["id"]);
+        w.start("place", &[("id", id)])?;
+        w.text_element("name", id)?;
+        if let Some(tokens) = net["initial_marking"].get(id) {
+            w.text_element("initialMarking", &tokens.to_string())?;
+        }
+        w.end("place")?;
+    }
+    for transition in net["transitions"].as_array().into_iter().flatten() {
+        let id = json_str(&transition["id"]);
+        w.start("transition", &[("id", id)])?;
+        match transition["label"].as_str() {
+            Some(label) => w.text_element("name", label)?,
+            None => {
+                w.text_element("name", "tau")?;
+                w.empty(
+                    "toolspecific",
+                    &[
+                        ("tool", "ProM"),
+                        ("version", "6.4"),
+                        ("activity", INVISIBLE_ACTIVITY),
+                        ("localNodeID", id),
+                    ],
+                )?;
+            }
+        }
+        w.end("transition")?;
+    }
+    for (i, arc) in net["arcs"].as_array().into_iter().flatten().enumerate() {
+        let id = format!("arc{i}");
+        w.start(
+            "arc",
+            &[
+                ("id", id.as_str()),
+                ("source", json_str(&arc["source"])),
+                ("target", json_str(&arc["target"])),
+            ],
+        )?;
+        if arc["weight"].as_u64().is_some_and(|weight| weight != 1) {
+            w.text_element("inscription", &arc["weight"].to_string())?;
+        }
+        w.end("arc")?;
+    }
+    w.end("page")?;
+    if let Some(markings) = net["final_markings"].as_array() {
+        w.start("finalmarkings", &[])?;
+        for marking in markings {
+            w.start("marking", &[])?;
+            for (place, tokens) in marking.as_object().into_iter().flatten() {
+                w.start("place", &[("idref", place.as_str())])?;
+                w.start("text", &[])?;
+                w.event(XMLEvent::Text(BytesText::new(&tokens.to_string())))?;
+                w.end("text")?;
+                w.end("place")?;
+            }
+            w.end("marking")?;
+        }
+        w.end("finalmarkings")?;
+    }
+    w.end("net")?;
+    w.end("pnml")?;
+    w.writer
+        .into_inner()
+        .flush()
+        .map_err(|e| format!("Failed to write PNML: {e}"))
+}
+
+///
+/// Export a [PetriNet] to a PNML file
+///
+pub fn export_pnml_file(net: &PetriNet, path: &str) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("Failed to create {path}: {e}"))?;
+    write_pnml(net, BufWriter::new(file))
+}
+
+///
+/// Import a Petri net from a PNML file
+///
+/// Returns a json-encoding of the Petri net (with `places`, `transitions`, `arcs`, `initial_marking` and `final_markings`)
+///
+/// * `path` - The filepath of the .pnml file to import
+///
+#[pyfunction]
+pub fn import_pnml_rs(path: String) -> PyResult<String> {
+    let net = import_pnml_file(&path).map_err(PyValueError::new_err)?;
+    Ok(petri_net_to_py(&net))
+}
+
+///
+/// Export a Petri net to a PNML file
+///
+/// * `net` - The json-encoding of the Petri net (e.g., as returned by `discover_alpha_rs` or `import_pnml_rs`)
+/// * `path` - The filepath the .pnml file should be written to
+///
+#[pyfunction]
+pub fn export_pnml_rs(net: String, path: String) -> PyResult<()> {
+    let net = petri_net_from_py(&net)?;
+    export_pnml_file(&net, &path).map_err(PyValueError::new_err)
+}
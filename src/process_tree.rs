@@ -0,0 +1,232 @@
+//! Process trees and their JSON encoding passed between Rust and Python
+//!
+//! Leaves are encoded as `{"label": ...}` (with a `null` label for silent leaves, i.e., tau),
+//! operator nodes as `{"operator": ..., "children": [...]}` with the operators `sequence`, `xor`, `parallel` and `loop`.
+use std::fmt::Display;
+
+use process_mining::{
+    petri_net::petri_net_struct::{ArcType, Marking, PlaceID},
+    PetriNet,
+};
+use pyo3::{exceptions::PyValueError, pyfunction, PyResult};
+use serde_json::{json, Value};
+
+use crate::petri_net::petri_net_to_py;
+
+/// Operator of an inner process tree node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeOperator {
+    /// Children are executed in order
+    Sequence,
+    /// Exactly one child is executed
+    Xor,
+    /// All children are executed concurrently
+    Parallel,
+    /// The first child (do) is executed, followed by any number of repetitions of one of the other children (redo) and the do child
+    Loop,
+}
+
+impl TreeOperator {
+    fn name(&self) -> &'static str {
+        match self {
+            TreeOperator::Sequence => "sequence",
+            TreeOperator::Xor => "xor",
+            TreeOperator::Parallel => "parallel",
+            TreeOperator::Loop => "loop",
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            TreeOperator::Sequence => "->",
+            TreeOperator::Xor => "X",
+            TreeOperator::Parallel => "+",
+            TreeOperator::Loop => "*",
+        }
+    }
+}
+
+/// A process tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessTree {
+    /// Leaf executing an activity
+    Activity(String),
+    /// Silent leaf
+    Tau,
+    /// Inner node with an operator and (at least two) children
+    Operator(TreeOperator, Vec<ProcessTree>),
+}
+
+/// Textual representation as used by pm4py (e.g., `->( 'a', X( tau, 'b' ) )`)
+impl Display for ProcessTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessTree::Activity(label) => write!(f, "'{label}'"),
+            ProcessTree::Tau => write!(f, "tau"),
+            ProcessTree::Operator(operator, children) => {
+                write!(f, "{}( ", operator.symbol())?;
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{child}")?;
+                }
+                write!(f, " )")
+            }
+        }
+    }
+}
+
+impl ProcessTree {
+    /// Labels of all activity leaves (in depth-first order)
+    pub fn activities(&self) -> Vec<&str> {
+        match self {
+            ProcessTree::Activity(label) => vec![label.as_str()],
+            ProcessTree::Tau => Vec::new(),
+            ProcessTree::Operator(_, children) => {
+                children.iter().flat_map(ProcessTree::activities).collect()
+            }
+        }
+    }
+
+    ///
+    /// Encode the process tree as JSON (see module documentation for the layout)
+    ///
+    pub fn to_json(&self) -> Value {
+        match self {
+            ProcessTree::Activity(label) => json!({ "label": label }),
+            ProcessTree::Tau => json!({ "label": null }),
+            ProcessTree::Operator(operator, children) => json!({
+                "operator": operator.name(),
+                "children": children.iter().map(ProcessTree::to_json).collect::<Vec<_>>(),
+            }),
+        }
+    }
+
+    ///
+    /// Decode a process tree from JSON (see module documentation for the layout)
+    ///
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        if let Some(operator) = value.get("operator") {
+            let operator = match operator.as_str() {
+                Some("sequence") => TreeOperator::Sequence,
+                Some("xor") => TreeOperator::Xor,
+                Some("parallel") => TreeOperator::Parallel,
+                Some("loop") => TreeOperator::Loop,
+                _ => return Err(format!("Invalid process tree operator {operator}")),
+            };
+            let children = value
+                .get("children")
+                .and_then(Value::as_array)
+                .ok_or_else(|| format!("Missing 'children' in {value}"))?
+                .iter()
+                .map(ProcessTree::from_json)
+                .collect::<Result<Vec<_>, String>>()?;
+            if children.is_empty() {
+                return Err(format!("Process tree operator '{}' without children", operator.name()));
+            }
+            return Ok(ProcessTree::Operator(operator, children));
+        }
+        match value.get("label") {
+            Some(Value::String(label)) => Ok(ProcessTree::Activity(label.clone())),
+            Some(Value::Null) => Ok(ProcessTree::Tau),
+            _ => Err(format!("Invalid process tree node {value}")),
+        }
+    }
+
+    /// Add the (sub-)tree to a [PetriNet], between the places `from` and `to`
+    fn add_to_petri_net(&self, net: &mut PetriNet, from: PlaceID, to: PlaceID) {
+        let add_transition = |net: &mut PetriNet, label: Option<String>, from, to| {
+            let transition = net.add_transition(label, None);
+            net.add_arc(ArcType::place_to_transition(from, transition), None);
+            net.add_arc(ArcType::transition_to_place(transition, to), None);
+        };
+        match self {
+            ProcessTree::Activity(label) => add_transition(net, Some(label.clone()), from, to),
+            ProcessTree::Tau => add_transition(net, None, from, to),
+            ProcessTree::Operator(TreeOperator::Sequence, children) => {
+                let mut current = from;
+                for (i, child) in children.iter().enumerate() {
+                    let next = if i == children.len() - 1 {
+                        to
+                    } else {
+                        net.add_place(None)
+                    };
+                    child.add_to_petri_net(net, current, next);
+                    current = next;
+                }
+            }
+            ProcessTree::Operator(TreeOperator::Xor, children) => {
+                for child in children {
+                    child.add_to_petri_net(net, from, to);
+                }
+            }
+            ProcessTree::Operator(TreeOperator::Parallel, children) => {
+                let split = net.add_transition(None, None);
+                let join = net.add_transition(None, None);
+                net.add_arc(ArcType::place_to_transition(from, split), None);
+                net.add_arc(ArcType::transition_to_place(join, to), None);
+                for child in children {
+                    let child_from = net.add_place(None);
+                    let child_to = net.add_place(None);
+                    net.add_arc(ArcType::transition_to_place(split, child_from), None);
+                    net.add_arc(ArcType::place_to_transition(child_to, join), None);
+                    child.add_to_petri_net(net, child_from, child_to);
+                }
+            }
+            ProcessTree::Operator(TreeOperator::Loop, children) => {
+                // Silent transitions entering and leaving the loop keep the do/redo places separate from `from` and `to`
+                let loop_start = net.add_place(None);
+                let loop_end = net.add_place(None);
+                add_transition(net, None, from, loop_start);
+                add_transition(net, None, loop_end, to);
+                children[0].add_to_petri_net(net, loop_start, loop_end);
+                for redo in &children[1..] {
+                    redo.add_to_petri_net(net, loop_end, loop_start);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Convert the process tree to a (sound, workflow) [PetriNet]
+    ///
+    /// Every activity leaf becomes a labeled transition, tau leaves and the routing of parallel and loop nodes become silent transitions.
+    /// The initial marking has a token in the source place, the final marking a token in the sink place.
+    ///
+    pub fn to_petri_net(&self) -> PetriNet {
+        let mut net = PetriNet::new();
+        let source = net.add_place(None);
+        let sink = net.add_place(None);
+        self.add_to_petri_net(&mut net, source, sink);
+        net.initial_marking = Some(Marking::from([(source, 1)]));
+        net.final_markings = Some(vec![Marking::from([(sink, 1)])]);
+        net
+    }
+}
+
+/// JSON-encoding of a [ProcessTree] returned to Python
+pub fn process_tree_to_py(tree: &ProcessTree) -> String {
+    tree.to_json().to_string()
+}
+
+/// Decode the JSON-encoding of a process tree passed from Python
+pub fn process_tree_from_py(tree: &str) -> PyResult<ProcessTree> {
+    let value: Value = serde_json::from_str(tree)
+        .map_err(|e| PyValueError::new_err(format!("Invalid process tree JSON: {e}")))?;
+    ProcessTree::from_json(&value)
+        .map_err(|e| PyValueError::new_err(format!("Invalid process tree: {e}")))
+}
+
+///
+/// Convert a process tree to a Petri net
+///
+/// Returns a json-encoding of the Petri net (with `places`, `transitions`, `arcs`, `initial_marking` and `final_markings`)
+///
+/// * `tree` - The json-encoding of the process tree (as returned by `discover_inductive_rs`)
+///
+#[pyfunction]
+pub fn process_tree_to_petri_net_rs(tree: String) -> PyResult<String> {
+    let tree = process_tree_from_py(&tree)?;
+    Ok(petri_net_to_py(&tree.to_petri_net()))
+}
@@ -6,18 +6,67 @@ mod xes_tests {
         time::Instant,
     };
 
+    use chrono::DateTime;
     use polars::{
-        datatypes::{AnyValue, Field},
+        datatypes::{AnyValue, DataType, Field, TimeUnit},
         error::PolarsError,
         frame::DataFrame,
         prelude::NamedFrom,
         series::Series,
     };
     use process_mining::{
-        convert_log_to_dataframe, event_log::{Trace, XESEditableAttribute}, import_ocel_xml_slice, import_xes_file, XESImportOptions
+        convert_log_to_dataframe, event_log::{AttributeValue, Trace, XESEditableAttribute}, import_ocel_xml_slice, import_xes_file, XESImportOptions
+    };
+    use process_mining::{
+        alphappp::full::{alphappp_discover_petri_net, AlphaPPPConfig},
+        event_log::activity_projection::EventLogActivityProjection,
+        PetriNet,
+    };
+    use process_mining::{
+        ocel::ocel_struct::{
+            OCELAttributeValue, OCELEvent, OCELObject, OCELObjectAttribute, OCELRelationship, OCELType,
+            OCELTypeAttribute,
+        },
+        OCEL,
     };
 
-    use crate::ocel::ocel2_to_df;
+    use crate::analysis::{
+        alignments::{align_log, alignment_log_fitness, AlignmentMove},
+        alpha::discover_alpha,
+        case_log_from_df,
+        dfg::discover_dfg,
+        inductive::discover_inductive,
+        summary::{log_summary, TimeBucket},
+        token_replay::{log_fitness, token_replay},
+        variants::{filter_top_variants, variants, variants_to_df, TopVariants},
+        CaseEvents, CaseLog, EventColumns,
+    };
+    use crate::cache::{cache_key, load_cached_df, store_cached_df, LOG_DATA_METADATA_KEY};
+    use crate::csv_log::{csv_df_to_log, CSVColumnMapping};
+    use crate::mxml::parse_mxml;
+    use crate::xes_json::{
+        event_log_from_json, event_log_to_json, export_xes_json_file, import_xes_json_file,
+    };
+    use crate::ocel::{
+        check_timestamps_in_range,
+        filter::{filter_ocel_types, slice_ocel, OCELTypeFilter, SliceObjectPolicy},
+        flatten::flatten_ocel,
+        from_xes::xes_to_ocel,
+        infer_df_options,
+        ocdfg::discover_ocdfg,
+        import_ocel_from_path,
+        ocel1::{is_ocel1_xml, parse_ocel1_json, parse_ocel1_xml},
+        parquet::{ocel_types_to_df, read_ocel_parquet, write_ocel_dfs_parquet, write_ocel_parquet},
+        ocel2_to_df, ocel2_to_df_with, ocel_dfs_to_ocel,
+        snapshot::{enrich_events_with_object_state, object_snapshot},
+        summary::ocel_summary,
+        validate::{apply_declared_types, declared_ocel_types, validate_ocel, DeclaredTypes},
+        InitialAttributePolicy, OCEL2DataFrameOptions, ObjectChangesFormat,
+    };
+    use crate::petri_net::{petri_net_from_json, petri_net_to_json, IndexedPetriNet};
+    use crate::pnml::{parse_pnml, write_pnml};
+    use crate::process_tree::ProcessTree;
+    use crate::time::{OutOfRangePolicy, TimestampOptions, TimestampUnit, TimezoneOption};
 
     #[test]
     fn basic_xes() {
@@ -70,7 +119,7 @@ mod xes_tests {
         let now = Instant::now();
         let ocel_bytes = include_bytes!("../test_data/ContainerLogistics.xml");
         let ocel = import_ocel_xml_slice(ocel_bytes);
-        let ocel_dfs = ocel2_to_df(&ocel);
+        let ocel_dfs = ocel2_to_df(&ocel).unwrap();
         println!(
             "Got OCEL DF with {:?} objects in {:?}; Object change shape: {:?}; O2O shape: {:?}; E2O shape: {:?}",
             ocel_dfs.objects.shape(),
@@ -90,7 +139,15 @@ mod xes_tests {
         let now = Instant::now();
         let ocel_bytes = include_bytes!("../test_data/order-management.xml");
         let ocel = import_ocel_xml_slice(ocel_bytes);
-        let ocel_dfs = ocel2_to_df(&ocel);
+        // PM4PY only considers attribute values at the Unix epoch as initial values
+        let ocel_dfs = ocel2_to_df_with(
+            &ocel,
+            &OCEL2DataFrameOptions {
+                initial_attributes: InitialAttributePolicy::EpochOnly,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         println!(
             "Got OCEL DF with {:?} objects in {:?}; Object change shape: {:?}; O2O shape: {:?}; E2O shape: {:?}",
             ocel_dfs.objects.shape(),
@@ -124,6 +181,1245 @@ mod xes_tests {
         assert_eq!(ocel_dfs.object_changes.shape(),(18604,7));
     }
 
+    /// Small OCEL with one `item` object (status set at the epoch, price changed from 5.0 to 7.0) related to two later events
+    fn small_ocel() -> OCEL {
+        let time = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+        let attribute = |name: &str, value: OCELAttributeValue, t: &str| OCELObjectAttribute {
+            name: name.to_string(),
+            value,
+            time: time(t),
+        };
+        let event = |id: &str, t: &str| OCELEvent {
+            id: id.to_string(),
+            event_type: "update".to_string(),
+            time: time(t),
+            attributes: Vec::new(),
+            relationships: vec![OCELRelationship {
+                object_id: "i1".to_string(),
+                qualifier: "item".to_string(),
+            }],
+        };
+        OCEL {
+            event_types: vec![OCELType {
+                name: "update".to_string(),
+                attributes: Vec::new(),
+            }],
+            object_types: vec![OCELType {
+                name: "item".to_string(),
+                attributes: vec![
+                    OCELTypeAttribute {
+                        name: "price".to_string(),
+                        value_type: "float".to_string(),
+                    },
+                    OCELTypeAttribute {
+                        name: "status".to_string(),
+                        value_type: "string".to_string(),
+                    },
+                ],
+            }],
+            events: vec![
+                event("e1", "2024-01-05T00:00:00Z"),
+                event("e2", "2024-01-06T00:00:00Z"),
+            ],
+            objects: vec![OCELObject {
+                id: "i1".to_string(),
+                object_type: "item".to_string(),
+                attributes: vec![
+                    attribute("status", OCELAttributeValue::String("new".to_string()), "1970-01-01T00:00:00Z"),
+                    attribute("price", OCELAttributeValue::Float(5.0), "2024-01-01T00:00:00Z"),
+                    attribute("price", OCELAttributeValue::Float(7.0), "2024-01-03T00:00:00Z"),
+                ],
+                relationships: Vec::new(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_ocel2_df_long_object_changes() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let ocel = import_ocel_xml_slice(ocel_bytes);
+        let ocel_dfs = ocel2_to_df_with(
+            &ocel,
+            &OCEL2DataFrameOptions {
+                object_changes_format: ObjectChangesFormat::Long,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let num_obj_attrs = ocel.objects.iter().map(|o| o.attributes.len()).sum::<usize>();
+        // oid, type, field, timestamp + 5 typed value columns
+        assert_eq!(ocel_dfs.object_changes.shape(), (num_obj_attrs, 9));
+        assert_eq!(ocel_dfs.object_changes.shape().0, 18604);
+
+        let ocel_dfs = ocel2_to_df_with(
+            &small_ocel(),
+            &OCEL2DataFrameOptions {
+                object_changes_format: ObjectChangesFormat::Long,
+                timezone: TimezoneOption::Naive,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let changes = &ocel_dfs.object_changes;
+        // Value columns keep their type even if no value of that type occurs
+        for (column, dtype) in [
+            ("ocel:value:string", DataType::String),
+            ("ocel:value:time", DataType::Datetime(TimeUnit::Nanoseconds, None)),
+            ("ocel:value:integer", DataType::Int64),
+            ("ocel:value:float", DataType::Float64),
+            ("ocel:value:boolean", DataType::Boolean),
+        ] {
+            assert_eq!(changes.column(column).unwrap().dtype(), &dtype);
+        }
+        // Rows are sorted by timestamp: status (epoch), price 5.0, price 7.0
+        assert_eq!(changes.column("ocel:field").unwrap().str().unwrap().get(1), Some("price"));
+        assert_eq!(changes.column("ocel:value:float").unwrap().f64().unwrap().get(1), Some(5.0));
+        assert_eq!(changes.column("ocel:value:string").unwrap().str().unwrap().get(1), None);
+        assert_eq!(changes.column("ocel:value:string").unwrap().str().unwrap().get(0), Some("new"));
+        assert_eq!(changes.column("ocel:value:integer").unwrap().null_count(), 3);
+    }
+
+    #[test]
+    fn test_ocel2_initial_attribute_policies() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let ocel = import_ocel_xml_slice(ocel_bytes);
+        for initial_attributes in [
+            InitialAttributePolicy::EpochOnly,
+            InitialAttributePolicy::EarliestValue,
+            InitialAttributePolicy::ValueAtFirstEvent,
+        ] {
+            let ocel_dfs = ocel2_to_df_with(
+                &ocel,
+                &OCEL2DataFrameOptions {
+                    initial_attributes,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            assert_eq!(ocel_dfs.objects.shape().0, ocel.objects.len());
+        }
+        // All objects with attributes have an earliest value for them
+        let ocel_dfs = ocel2_to_df(&ocel).unwrap();
+        assert!(ocel_dfs.objects.shape().1 > 2);
+
+        // The price is set after the epoch and changes before the first event of the object
+        let ocel = small_ocel();
+        let initial_values = |initial_attributes: InitialAttributePolicy| {
+            let objects = ocel2_to_df_with(
+                &ocel,
+                &OCEL2DataFrameOptions {
+                    initial_attributes,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .objects;
+            let status = objects.column("status").unwrap().str().unwrap().get(0).map(String::from);
+            let price = objects.column("price").ok().and_then(|c| c.f64().unwrap().get(0));
+            (status, price)
+        };
+        let new = Some("new".to_string());
+        assert_eq!(initial_values(InitialAttributePolicy::EpochOnly), (new.clone(), None));
+        assert_eq!(initial_values(InitialAttributePolicy::EarliestValue), (new.clone(), Some(5.0)));
+        assert_eq!(initial_values(InitialAttributePolicy::ValueAtFirstEvent), (new, Some(7.0)));
+    }
+
+    #[test]
+    fn test_ocel2_object_snapshot() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let ocel = import_ocel_xml_slice(ocel_bytes);
+        let ocel_dfs = ocel2_to_df(&ocel).unwrap();
+
+        // Round-trip through the DataFrame representation
+        let ocel_rt = ocel_dfs_to_ocel(&ocel_dfs).unwrap();
+        assert_eq!(ocel_rt.events.len(), ocel.events.len());
+        assert_eq!(ocel_rt.objects.len(), ocel.objects.len());
+
+        let last_time = ocel.events.iter().map(|e| e.time).max().unwrap();
+        let snapshot = object_snapshot(&ocel_rt, &last_time, &TimestampOptions::default()).unwrap();
+        assert_eq!(snapshot.shape().0, ocel.objects.len());
+
+        let enriched = enrich_events_with_object_state(&ocel_rt, &TimestampOptions::default()).unwrap();
+        assert_eq!(enriched.shape().0, ocel_dfs.e2o.shape().0);
+
+        // As-of values before, between and after the two price changes
+        let ocel = small_ocel();
+        for (at, price) in [
+            ("2023-12-31T00:00:00Z", None),
+            ("2024-01-02T00:00:00Z", Some(5.0)),
+            ("2024-01-04T00:00:00Z", Some(7.0)),
+        ] {
+            let at = DateTime::parse_from_rfc3339(at).unwrap();
+            let snapshot = object_snapshot(&ocel, &at, &TimestampOptions::default()).unwrap();
+            assert_eq!(snapshot.column("price").unwrap().f64().unwrap().get(0), price);
+            assert_eq!(snapshot.column("status").unwrap().str().unwrap().get(0), Some("new"));
+        }
+        let enriched = enrich_events_with_object_state(&ocel, &TimestampOptions::default()).unwrap();
+        assert_eq!(enriched.column("price").unwrap().f64().unwrap().get(0), Some(7.0));
+
+        // Invalid timestamps are reported instead of being replaced
+        let mut ocel_dfs = ocel2_to_df(&ocel).unwrap();
+        ocel_dfs
+            .events
+            .with_column(Series::new("ocel:timestamp".into(), vec!["a", "b"]))
+            .unwrap();
+        let err = ocel_dfs_to_ocel(&ocel_dfs).unwrap_err().to_string();
+        assert!(err.contains("ocel:timestamp") && err.contains("row 0"));
+
+        // Null object ids are rejected instead of becoming objects with id ""
+        let mut ocel_dfs = ocel2_to_df(&ocel).unwrap();
+        let num_relations = ocel_dfs.e2o.height();
+        ocel_dfs
+            .e2o
+            .with_column(Series::full_null("ocel:oid".into(), num_relations, &DataType::String))
+            .unwrap();
+        let err = ocel_dfs_to_ocel(&ocel_dfs).unwrap_err().to_string();
+        assert!(err.contains("ocel:oid") && err.contains("row 0"));
+
+        // Declared object types determine the attribute columns (also for attributes without values)
+        let mut ocel = ocel_dfs_to_ocel(&ocel2_to_df(&ocel).unwrap()).unwrap();
+        let declared: DeclaredTypes = HashMap::from([(
+            "item".to_string(),
+            HashMap::from([
+                ("status".to_string(), "string".to_string()),
+                ("price".to_string(), "float".to_string()),
+                ("weight".to_string(), "float".to_string()),
+            ]),
+        )]);
+        apply_declared_types(&mut ocel, None, Some(&declared));
+        let at = DateTime::parse_from_rfc3339("2024-01-04T00:00:00Z").unwrap();
+        let snapshot = object_snapshot(&ocel, &at, &TimestampOptions::default()).unwrap();
+        assert_eq!(snapshot.column("weight").unwrap().dtype(), &DataType::Float64);
+        assert_eq!(snapshot.column("price").unwrap().f64().unwrap().get(0), Some(7.0));
+        let enriched = enrich_events_with_object_state(&ocel, &TimestampOptions::default()).unwrap();
+        assert_eq!(enriched.column("weight").unwrap().dtype(), &DataType::Float64);
+    }
+
+    #[test]
+    fn test_ocel2_validation() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let mut ocel = import_ocel_xml_slice(ocel_bytes);
+        let report = validate_ocel(&ocel);
+        assert!(report.dangling_e2o.is_empty());
+        assert!(report.duplicate_event_ids.is_empty());
+
+        ocel.events[0].relationships[0].object_id = "unknown-object".to_string();
+        let duplicate = ocel.objects[0].clone();
+        ocel.objects.push(duplicate);
+        let report = validate_ocel(&ocel);
+        assert_eq!(report.dangling_e2o.len(), 1);
+        assert_eq!(report.dangling_e2o[0].object_id, "unknown-object");
+        assert_eq!(report.duplicate_object_ids, vec![ocel.objects[0].id.clone()]);
+        assert!(!report.is_valid());
+
+        // Invalid OCEL given as DataFrames
+        let mut ocel = small_ocel();
+        ocel.events[0].relationships[0].object_id = "unknown-object".to_string();
+        ocel.events[1].relationships.clear();
+        let ocel_dfs = ocel2_to_df(&ocel).unwrap();
+        let mut ocel_rt = ocel_dfs_to_ocel(&ocel_dfs).unwrap();
+        let report = validate_ocel(&ocel_rt);
+        assert_eq!(report.dangling_e2o.len(), 1);
+        assert_eq!(report.dangling_e2o[0].source_id, "e1");
+        assert_eq!(report.events_without_objects, vec!["e2".to_string()]);
+        // Types derived from the DataFrames always match the data
+        assert!(report.attribute_type_mismatches.is_empty());
+
+        ocel_rt.object_types = declared_ocel_types(&HashMap::from([(
+            "item".to_string(),
+            HashMap::from([("price".to_string(), "integer".to_string())]),
+        )]));
+        let report = validate_ocel(&ocel_rt);
+        assert_eq!(report.attribute_type_mismatches.len(), 2);
+        assert_eq!(report.attribute_type_mismatches[0].expected, "integer");
+        assert_eq!(report.attribute_type_mismatches[0].actual, "float");
+        assert_eq!(report.undeclared_object_attributes.len(), 1);
+        assert_eq!(report.undeclared_object_attributes[0].attribute, "status");
+    }
+
+    #[test]
+    fn test_ocel2_out_of_range_timestamps() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let mut ocel = import_ocel_xml_slice(ocel_bytes);
+        ocel.events[0].time = DateTime::parse_from_rfc3339("0001-01-01T00:00:00+00:00").unwrap();
+
+        let nanos = TimestampOptions::default();
+        assert!(check_timestamps_in_range(&ocel, &nanos).is_err());
+        // The error policy is enforced by the conversions themselves
+        assert!(ocel2_to_df(&ocel).is_err());
+        assert!(enrich_events_with_object_state(&ocel, &nanos).is_err());
+        let last_time = ocel.events.iter().map(|e| e.time).max().unwrap();
+        assert!(object_snapshot(&ocel, &last_time, &nanos).is_err());
+        let null_policy = TimestampOptions {
+            out_of_range: OutOfRangePolicy::Null,
+            ..Default::default()
+        };
+        let enriched = enrich_events_with_object_state(&ocel, &null_policy).unwrap();
+        assert_eq!(enriched.column("ocel:timestamp").unwrap().null_count(), ocel.events[0].relationships.len());
+
+        let nulled = ocel2_to_df_with(
+            &ocel,
+            &OCEL2DataFrameOptions {
+                timestamps: TimestampOptions {
+                    out_of_range: OutOfRangePolicy::Null,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(nulled.events.column("ocel:timestamp").unwrap().null_count(), 1);
+
+        let micros = TimestampOptions {
+            unit: TimestampUnit::Microseconds,
+            ..Default::default()
+        };
+        assert!(check_timestamps_in_range(&ocel, &micros).is_ok());
+    }
+
+    #[test]
+    fn test_ocel2_timezone() {
+        let ocel = small_ocel();
+        let timestamp_dtype = |timezone: TimezoneOption| {
+            ocel2_to_df_with(
+                &ocel,
+                &OCEL2DataFrameOptions {
+                    timezone,
+                    ..Default::default()
+                },
+            )
+            .map(|dfs| dfs.events.column("ocel:timestamp").unwrap().dtype().clone())
+        };
+        // Timezone-aware UTC by default (as for event log imports)
+        assert!(matches!(timestamp_dtype(TimezoneOption::default()).unwrap(), DataType::Datetime(_, Some(_))));
+        assert_eq!(
+            timestamp_dtype(TimezoneOption::Naive).unwrap(),
+            DataType::Datetime(TimeUnit::Nanoseconds, None)
+        );
+        assert!(timestamp_dtype(TimezoneOption::Named("Invalid/Timezone".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_ocel2_type_filter() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let mut ocel = import_ocel_xml_slice(ocel_bytes);
+        let filter = OCELTypeFilter {
+            object_types: Some(HashSet::from_iter(["orders".to_string(), "items".to_string()])),
+            exclude_event_types: HashSet::from_iter(["pay order".to_string()]),
+            ..Default::default()
+        };
+        filter_ocel_types(&mut ocel, &filter);
+        assert!(ocel
+            .objects
+            .iter()
+            .all(|o| o.object_type == "orders" || o.object_type == "items"));
+        assert!(ocel.events.iter().all(|e| e.event_type != "pay order"));
+
+        let report = validate_ocel(&ocel);
+        assert!(report.dangling_e2o.is_empty());
+        assert!(report.dangling_o2o.is_empty());
+        assert!(report.events_without_objects.is_empty());
+    }
+
+    #[test]
+    fn test_ocel2_slice() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let ocel = import_ocel_xml_slice(ocel_bytes);
+        let mut times: Vec<_> = ocel.events.iter().map(|e| e.time).collect();
+        times.sort();
+        let (start, end) = (times[times.len() / 4], times[times.len() / 2]);
+        for policy in [
+            SliceObjectPolicy::Related,
+            SliceObjectPolicy::Touched,
+            SliceObjectPolicy::Contained,
+        ] {
+            let sliced = slice_ocel(&ocel, &start, &end, policy);
+            assert!(sliced.events.iter().all(|e| e.time >= start && e.time < end));
+            let report = validate_ocel(&sliced);
+            assert!(report.dangling_e2o.is_empty());
+            assert!(report.dangling_o2o.is_empty());
+        }
+        let touched = slice_ocel(&ocel, &start, &end, SliceObjectPolicy::Touched);
+        let contained = slice_ocel(&ocel, &start, &end, SliceObjectPolicy::Contained);
+        assert!(contained.objects.len() <= touched.objects.len());
+    }
+
+    #[test]
+    fn test_ocel2_infer_df_options() {
+        let ocel = small_ocel();
+        let options = OCEL2DataFrameOptions {
+            object_changes_format: ObjectChangesFormat::Long,
+            timezone: TimezoneOption::Naive,
+            timestamps: TimestampOptions {
+                unit: TimestampUnit::Microseconds,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let ocel_dfs = ocel2_to_df_with(&ocel, &options).unwrap();
+        let inferred = infer_df_options(&ocel_dfs);
+        assert_eq!(inferred.object_changes_format, ObjectChangesFormat::Long);
+        assert_eq!(inferred.timezone, TimezoneOption::Naive);
+        assert_eq!(inferred.timestamps.unit, TimestampUnit::Microseconds);
+        // Slicing with the inferred options keeps the shape of the input DataFrames
+        let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        let end = DateTime::parse_from_rfc3339("2024-02-01T00:00:00Z").unwrap();
+        let sliced = slice_ocel(
+            &ocel_dfs_to_ocel(&ocel_dfs).unwrap(),
+            &start,
+            &end,
+            SliceObjectPolicy::Related,
+        );
+        let sliced_dfs = ocel2_to_df_with(&sliced, &inferred).unwrap();
+        assert_eq!(
+            sliced_dfs.object_changes.get_column_names(),
+            ocel_dfs.object_changes.get_column_names()
+        );
+        assert_eq!(
+            sliced_dfs.events.column("ocel:timestamp").unwrap().dtype(),
+            &DataType::Datetime(TimeUnit::Microseconds, None)
+        );
+        assert_eq!(
+            infer_df_options(&ocel2_to_df(&ocel).unwrap()).timezone,
+            TimezoneOption::Utc
+        );
+    }
+
+    #[test]
+    fn test_ocel2_flatten() {
+        let ocel_bytes = include_bytes!("../test_data/order-management.xml");
+        let ocel = import_ocel_xml_slice(ocel_bytes);
+        let log = flatten_ocel(&ocel, "orders");
+        let num_orders = ocel
+            .objects
+            .iter()
+            .filter(|o| o.object_type == "orders")
+            .count();
+        assert_eq!(log.traces.len(), num_orders);
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        assert_eq!(
+            df.shape().0,
+            log.traces.iter().map(|t| t.events.len()).sum::<usize>()
+        );
+        assert!(df.column("case:concept:name").is_ok());
+    }
+
+    #[test]
+    fn test_xes_to_ocel() {
+        let log = import_xes_file("test.xes", XESImportOptions::default()).unwrap();
+        let ocel = xes_to_ocel(&log, "case", &["system".to_string()]).unwrap();
+        assert_eq!(
+            ocel.events.len(),
+            log.traces.iter().map(|t| t.events.len()).sum::<usize>()
+        );
+        assert_eq!(
+            ocel.objects
+                .iter()
+                .filter(|o| o.object_type == "case")
+                .count(),
+            log.traces.len()
+        );
+        assert!(validate_ocel(&ocel).dangling_e2o.is_empty());
+    }
+
+    #[test]
+    fn test_xes_to_ocel_id_collision() {
+        let event = |activity: &str, resource: &str, time: Option<&str>| {
+            let mut attributes = vec![
+                serde_json::json!({"key": "concept:name", "type": "string", "value": activity}),
+                serde_json::json!({"key": "org:resource", "type": "string", "value": resource}),
+            ];
+            if let Some(time) = time {
+                attributes.push(serde_json::json!({"key": "time:timestamp", "type": "date", "value": time}));
+            }
+            serde_json::json!({"attributes": attributes})
+        };
+        let log_json = |events: Vec<serde_json::Value>| {
+            serde_json::json!({
+                "traces": [{
+                    "attributes": [{"key": "concept:name", "type": "string", "value": "1"}],
+                    "events": events,
+                }]
+            })
+        };
+        // Case "1" and resource "1" are different objects
+        let log = event_log_from_json(&log_json(vec![
+            event("a", "1", Some("2024-01-01T00:00:00Z")),
+            event("b", "2", Some("2024-01-02T00:00:00Z")),
+        ]))
+        .unwrap();
+        let ocel = xes_to_ocel(&log, "case", &["org:resource".to_string()]).unwrap();
+        let mut object_ids: Vec<_> = ocel.objects.iter().map(|o| o.id.as_str()).collect();
+        object_ids.sort();
+        assert_eq!(object_ids, ["case:1", "org:resource:1", "org:resource:2"]);
+        assert_eq!(
+            ocel.events[0]
+                .relationships
+                .iter()
+                .map(|r| r.object_id.as_str())
+                .collect::<Vec<_>>(),
+            ["case:1", "org:resource:1"]
+        );
+        assert!(validate_ocel(&ocel).dangling_e2o.is_empty());
+
+        // Events without timestamp are rejected (instead of being placed at the epoch)
+        let log = event_log_from_json(&log_json(vec![event("a", "1", None)])).unwrap();
+        assert!(xes_to_ocel(&log, "case", &["org:resource".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_ocel1_import() {
+        let json = serde_json::json!({
+            "ocel:global-event": {},
+            "ocel:global-object": {},
+            "ocel:global-log": {},
+            "ocel:events": {
+                "e1": {
+                    "ocel:activity": "place order",
+                    "ocel:timestamp": "2020-01-01T10:00:00+00:00",
+                    "ocel:omap": ["o1", "i1"],
+                    "ocel:vmap": {"price": 12.5}
+                }
+            },
+            "ocel:objects": {
+                "o1": {"ocel:type": "order", "ocel:ovmap": {"customer": "c1"}},
+                "i1": {"ocel:type": "item", "ocel:ovmap": {}}
+            }
+        });
+        let ocel = parse_ocel1_json(&json).unwrap();
+        assert_eq!(ocel.events.len(), 1);
+        assert_eq!(ocel.events[0].relationships.len(), 2);
+        assert_eq!(ocel.objects.len(), 2);
+        assert_eq!(ocel.event_types.len(), 1);
+        assert_eq!(ocel.object_types.len(), 2);
+        assert!(validate_ocel(&ocel).is_valid());
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<log>
+  <events>
+    <event>
+      <string key="id" value="e1"/>
+      <string key="activity" value="place order"/>
+      <date key="timestamp" value="2020-01-01T10:00:00+00:00"/>
+      <list key="omap">
+        <string key="object-id" value="o1"/>
+      </list>
+      <list key="vmap">
+        <float key="price" value="12.5"/>
+      </list>
+    </event>
+  </events>
+  <objects>
+    <object>
+      <string key="id" value="o1"/>
+      <string key="type" value="order"/>
+      <list key="ovmap">
+        <string key="customer" value="c1"/>
+      </list>
+    </object>
+  </objects>
+</log>"#;
+        let ocel = parse_ocel1_xml(xml.as_bytes()).unwrap();
+        assert_eq!(ocel.events.len(), 1);
+        assert_eq!(ocel.events[0].event_type, "place order");
+        assert_eq!(ocel.events[0].relationships[0].object_id, "o1");
+        assert_eq!(ocel.objects[0].attributes.len(), 1);
+        assert!(validate_ocel(&ocel).is_valid());
+
+        // XML-OCEL files saved as `.xml` are detected by their root element
+        assert!(is_ocel1_xml(xml.as_bytes()));
+        assert!(!is_ocel1_xml(&include_bytes!("../test_data/order-management.xml")[..]));
+        let path = std::env::temp_dir().join("rustxes-test-ocel1.xml");
+        std::fs::write(&path, xml).unwrap();
+        let imported = import_ocel_from_path(path.to_str().unwrap()).unwrap();
+        assert_eq!(imported.events.len(), 1);
+        assert_eq!(imported.objects.len(), ocel.objects.len());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_csv_log() {
+        let df = DataFrame::new(vec![
+            Series::new("CaseID".into(), &["c2", "c1", "c2"]).into(),
+            Series::new("Activity".into(), &["B", "A", "A"]).into(),
+            Series::new(
+                "Timestamp".into(),
+                &["02.01.2024 10:00", "01.01.2024 09:00", "01.01.2024 12:00"],
+            )
+            .into(),
+            Series::new("case:channel".into(), &["web", "shop", "web"]).into(),
+            Series::new("cost".into(), &[2.5, 1.0, 3.0]).into(),
+        ])
+        .unwrap();
+        let mapping = CSVColumnMapping {
+            case_col: "CaseID".to_string(),
+            activity_col: "Activity".to_string(),
+            timestamp_col: "Timestamp".to_string(),
+            resource_col: None,
+            case_attribute_cols: Vec::new(),
+        };
+        let log = csv_df_to_log(&df, &mapping, Some("%d.%m.%Y %H:%M")).unwrap();
+        assert_eq!(log.traces.len(), 2);
+        // Events are sorted by timestamp within a case
+        let activities: Vec<_> = log.traces[0]
+            .events
+            .iter()
+            .map(|e| e.attributes[0].value.clone())
+            .collect();
+        assert!(matches!(&activities[0], AttributeValue::String(a) if a == "A"));
+        assert!(matches!(&activities[1], AttributeValue::String(a) if a == "B"));
+        let converted = convert_log_to_dataframe(&log, false).unwrap();
+        assert_eq!(converted.height(), 3);
+        for col in [
+            "case:concept:name",
+            "case:channel",
+            "concept:name",
+            "time:timestamp",
+            "cost",
+        ] {
+            assert!(converted.column(col).is_ok(), "Missing column {col}");
+        }
+        assert!(csv_df_to_log(&df, &mapping, None).is_err());
+    }
+
+    #[test]
+    fn test_mxml() {
+        let mxml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<WorkflowLog>
+  <Source program="test"/>
+  <Process id="p">
+    <ProcessInstance id="case1">
+      <Data><Attribute name="priority">high</Attribute></Data>
+      <AuditTrailEntry>
+        <Data><Attribute name="cost">10</Attribute></Data>
+        <WorkflowModelElement>register</WorkflowModelElement>
+        <EventType>complete</EventType>
+        <Timestamp>2006-01-01T10:00:00.000+01:00</Timestamp>
+        <Originator>Alice</Originator>
+      </AuditTrailEntry>
+      <AuditTrailEntry>
+        <WorkflowModelElement>check</WorkflowModelElement>
+        <EventType unknowntype="custom">unknown</EventType>
+        <Timestamp>2006-01-01T11:00:00.000+0100</Timestamp>
+      </AuditTrailEntry>
+    </ProcessInstance>
+    <ProcessInstance id="case2"/>
+  </Process>
+</WorkflowLog>"#;
+        let log = parse_mxml(mxml.as_bytes()).unwrap();
+        assert_eq!(log.traces.len(), 2);
+        assert_eq!(log.traces[0].attributes.len(), 2);
+        assert_eq!(log.traces[0].events.len(), 2);
+        assert!(log.traces[0].events[1].attributes.iter().any(|a| {
+            a.key == "lifecycle:transition"
+                && matches!(&a.value, AttributeValue::String(t) if t == "custom")
+        }));
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        for col in [
+            "case:concept:name",
+            "case:priority",
+            "concept:name",
+            "lifecycle:transition",
+            "time:timestamp",
+            "org:resource",
+        ] {
+            assert!(df.column(col).is_ok(), "Missing column {col}");
+        }
+    }
+
+    #[test]
+    fn test_parquet_cache() {
+        let key = cache_key("test.xes", "options").unwrap();
+        assert_eq!(key, cache_key("test.xes", "options").unwrap());
+        assert_ne!(key, cache_key("test.xes", "other options").unwrap());
+
+        let log = import_xes_file("test.xes", XESImportOptions::default()).unwrap();
+        let mut df = convert_log_to_dataframe(&log, false).unwrap();
+        let cache_dir = std::env::temp_dir().join("rustxes-test-cache");
+        let _ = std::fs::remove_dir_all(&cache_dir);
+        let cache_dir = cache_dir.to_str().unwrap();
+        assert!(load_cached_df(cache_dir, &key, "xes").is_none());
+        store_cached_df(
+            cache_dir,
+            &key,
+            "xes",
+            &mut df,
+            vec![(LOG_DATA_METADATA_KEY.to_string(), "{}".to_string())],
+        )
+        .unwrap();
+        let (cached_df, metadata) = load_cached_df(cache_dir, &key, "xes").unwrap();
+        assert!(cached_df.equals_missing(&df));
+        assert_eq!(metadata.get(LOG_DATA_METADATA_KEY).unwrap(), "{}");
+        // Only the renamed cache file remains (no temporary files)
+        assert_eq!(std::fs::read_dir(cache_dir).unwrap().count(), 1);
+
+        // Keys of the same file share the path hash; storing a new entry removes the outdated one
+        let other_key = cache_key("test.xes", "other options").unwrap();
+        assert_eq!(key.split_once('-').unwrap().0, other_key.split_once('-').unwrap().0);
+        store_cached_df(cache_dir, &other_key, "xes", &mut df, Vec::new()).unwrap();
+        assert!(load_cached_df(cache_dir, &key, "xes").is_none());
+        assert!(load_cached_df(cache_dir, &other_key, "xes").is_some());
+        assert_eq!(std::fs::read_dir(cache_dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_ocel_parquet() {
+        let ocel = import_ocel_xml_slice(include_bytes!("../test_data/order-management.xml"));
+        let dir = std::env::temp_dir().join("rustxes-test-ocel-parquet");
+        let _ = std::fs::remove_dir_all(&dir);
+        write_ocel_parquet(&ocel, &dir).unwrap();
+        let read = read_ocel_parquet(&dir).unwrap();
+        assert_eq!(read.events.len(), ocel.events.len());
+        assert_eq!(read.objects.len(), ocel.objects.len());
+        assert_eq!(read.event_types.len(), ocel.event_types.len());
+        assert_eq!(read.object_types.len(), ocel.object_types.len());
+        for (a, b) in read.object_types.iter().zip(&ocel.object_types) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.attributes.len(), b.attributes.len());
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // Declared types (e.g., attributes without values) survive the DataFrame round trip
+        let mut ocel_dfs = ocel2_to_df(&small_ocel()).unwrap();
+        let mut ocel = ocel_dfs_to_ocel(&ocel_dfs).unwrap();
+        let declared: DeclaredTypes = HashMap::from([(
+            "item".to_string(),
+            HashMap::from([
+                ("price".to_string(), "float".to_string()),
+                ("status".to_string(), "string".to_string()),
+                ("weight".to_string(), "float".to_string()),
+            ]),
+        )]);
+        apply_declared_types(&mut ocel, None, Some(&declared));
+        let mut types = ocel_types_to_df(&ocel).unwrap();
+        write_ocel_dfs_parquet(&dir, &mut ocel_dfs, &mut types).unwrap();
+        let read = read_ocel_parquet(&dir).unwrap();
+        let type_attributes = |types: &[OCELType]| -> Vec<(String, String, String)> {
+            types
+                .iter()
+                .flat_map(|t| {
+                    t.attributes
+                        .iter()
+                        .map(|a| (t.name.clone(), a.name.clone(), a.value_type.clone()))
+                })
+                .collect()
+        };
+        assert_eq!(
+            type_attributes(&read.object_types),
+            type_attributes(&declared_ocel_types(&declared))
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // Only directories are supported as target
+        let file = std::env::temp_dir().join("rustxes-test-ocel.parquet");
+        assert!(write_ocel_dfs_parquet(&file, &mut ocel_dfs, &mut types).is_err());
+    }
+
+    #[test]
+    fn test_xes_json_roundtrip() {
+        let log = import_xes_file("test.xes", XESImportOptions::default()).unwrap();
+        let json = event_log_to_json(&log);
+        let roundtrip = event_log_from_json(&json).unwrap();
+        assert_eq!(roundtrip.traces.len(), log.traces.len());
+        assert_eq!(roundtrip.attributes.len(), log.attributes.len());
+        assert_eq!(json, event_log_to_json(&roundtrip));
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        let roundtrip_df = convert_log_to_dataframe(&roundtrip, false).unwrap();
+        assert_eq!(df.shape(), roundtrip_df.shape());
+    }
+
+    #[test]
+    fn test_xes_json_nested_attributes_roundtrip() {
+        let json = serde_json::json!({
+            "attributes": [{"key": "concept:name", "type": "string", "value": "log"}],
+            "extensions": [],
+            "classifiers": [],
+            "global_trace_attrs": [],
+            "global_event_attrs": [],
+            "traces": [{
+                "attributes": [
+                    {"key": "concept:name", "type": "string", "value": "1"},
+                    {"key": "identity:id", "type": "id", "value": "4a0bd5e5-1b28-4c5e-8d6f-2f5b9f0c7e11"},
+                ],
+                "events": [{
+                    "attributes": [
+                        {"key": "concept:name", "type": "string", "value": "a"},
+                        {"key": "time:timestamp", "type": "date", "value": "2024-01-01T10:00:00+01:00"},
+                        {"key": "items", "type": "list", "value": [
+                            {"key": "item", "type": "int", "value": 1},
+                            {"key": "item", "type": "float", "value": 2.5},
+                        ]},
+                        {"key": "address", "type": "container", "value": [
+                            {"key": "city", "type": "string", "value": "Aachen"},
+                            {"key": "geo", "type": "container", "value": [
+                                {"key": "valid", "type": "boolean", "value": true},
+                            ]},
+                        ]},
+                    ]
+                }]
+            }]
+        });
+        let log = event_log_from_json(&json).unwrap();
+        assert_eq!(event_log_to_json(&log), json);
+        let dir = std::env::temp_dir().join("rustxes-test-xes-json");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for file_name in ["log.json", "log.json.gz"] {
+            let path = dir.join(file_name);
+            let path = path.to_str().unwrap();
+            export_xes_json_file(&log, path).unwrap();
+            let roundtrip = import_xes_json_file(path).unwrap();
+            assert_eq!(event_log_to_json(&roundtrip), json);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_dfg() {
+        let log = import_xes_file("test.xes", XESImportOptions::default()).unwrap();
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        let case_log = case_log_from_df(&df, &EventColumns::default()).unwrap();
+        assert_eq!(case_log.cases.len(), log.traces.len());
+        let dfg = discover_dfg(&case_log, true);
+        let num_events: usize = log.traces.iter().map(|t| t.events.len()).sum();
+        assert_eq!(dfg.activity_frequencies.iter().sum::<usize>(), num_events);
+        assert_eq!(
+            dfg.edges.values().map(|e| e.frequency).sum::<usize>(),
+            num_events - log.traces.iter().filter(|t| !t.events.is_empty()).count()
+        );
+        let (nodes, edges) = dfg.to_dfs(true).unwrap();
+        assert_eq!(nodes.height(), case_log.activities.len());
+        assert_eq!(edges.height(), dfg.edges.len());
+        assert!(edges.column("median_duration").is_ok());
+    }
+
+    #[test]
+    fn test_ocdfg() {
+        let ocel = import_ocel_xml_slice(include_bytes!("../test_data/order-management.xml"));
+        let ocel_dfs = ocel2_to_df(&ocel).unwrap();
+        let (nodes, edges) = discover_ocdfg(&ocel_dfs.e2o).unwrap();
+        let related_objects: HashSet<&String> = ocel
+            .events
+            .iter()
+            .flat_map(|e| e.relationships.iter().map(|r| &r.object_id))
+            .collect();
+        let object_types: HashSet<&str> = ocel
+            .objects
+            .iter()
+            .filter(|o| related_objects.contains(&o.id))
+            .map(|o| o.object_type.as_str())
+            .collect();
+        let start_objects: u64 = nodes.column("start_objects").unwrap().u64().unwrap().sum().unwrap();
+        let end_objects: u64 = nodes.column("end_objects").unwrap().u64().unwrap().sum().unwrap();
+        assert_eq!(start_objects, end_objects);
+        assert!(start_objects > 0);
+        let node_types: HashSet<String> = nodes
+            .column("ocel:type")
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.unwrap().to_string())
+            .collect();
+        assert_eq!(node_types.len(), object_types.len());
+        assert!(edges.height() > 0);
+    }
+
+    #[test]
+    fn test_variants() {
+        let log = import_xes_file("test.xes", XESImportOptions::default()).unwrap();
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        let case_log = case_log_from_df(&df, &EventColumns::default()).unwrap();
+        let variants = variants(&case_log);
+        assert_eq!(
+            variants.iter().map(|v| v.cases.len()).sum::<usize>(),
+            case_log.cases.len()
+        );
+        assert!(variants
+            .windows(2)
+            .all(|w| w[0].cases.len() >= w[1].cases.len()));
+        let variants_df = variants_to_df(&case_log, &variants).unwrap();
+        assert_eq!(variants_df.height(), variants.len());
+
+        let top = filter_top_variants(&df, &EventColumns::default(), TopVariants::Count(1)).unwrap();
+        let top_log = case_log_from_df(&top, &EventColumns::default()).unwrap();
+        assert_eq!(top_log.cases.len(), variants[0].cases.len());
+        let all = filter_top_variants(&df, &EventColumns::default(), TopVariants::Coverage(1.0))
+            .unwrap();
+        assert_eq!(all.height(), df.height());
+    }
+
+    #[test]
+    fn test_log_summary() {
+        let log = import_xes_file("test.xes", XESImportOptions::default()).unwrap();
+        let df = convert_log_to_dataframe(&log, false).unwrap();
+        let summary: HashMap<&str, DataFrame> =
+            log_summary(&df, &EventColumns::default(), "org:resource", TimeBucket::Day)
+                .unwrap()
+                .into_iter()
+                .collect();
+        let overview = &summary["overview"];
+        assert_eq!(
+            overview.column("cases").unwrap().u64().unwrap().get(0),
+            Some(log.traces.len() as u64)
+        );
+        assert_eq!(
+            overview.column("events").unwrap().u64().unwrap().get(0),
+            Some(df.height() as u64)
+        );
+        let trace_length_cases: u64 = summary["trace_lengths"]
+            .column("cases")
+            .unwrap()
+            .u64()
+            .unwrap()
+            .sum()
+            .unwrap();
+        assert_eq!(trace_length_cases, log.traces.len() as u64);
+        let bucket_events: u64 = summary["events_per_bucket"]
+            .column("events")
+            .unwrap()
+            .u64()
+            .unwrap()
+            .sum()
+            .unwrap();
+        assert!(bucket_events <= df.height() as u64);
+        assert_eq!(summary["attributes"].height(), df.width());
+
+        // 2024-05-15 (Wednesday) 13:45 UTC
+        let t = DateTime::parse_from_rfc3339("2024-05-15T13:45:00Z").unwrap().timestamp_millis();
+        let start = |bucket: TimeBucket| {
+            DateTime::from_timestamp_millis(bucket.bucket_start(t).unwrap())
+                .unwrap()
+                .to_rfc3339()
+        };
+        assert_eq!(start(TimeBucket::Hour), "2024-05-15T13:00:00+00:00");
+        assert_eq!(start(TimeBucket::Week), "2024-05-13T00:00:00+00:00");
+        assert_eq!(start(TimeBucket::Month), "2024-05-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_ocel_summary() {
+        let ocel = import_ocel_xml_slice(include_bytes!("../test_data/order-management.xml"));
+        let summary: HashMap<&str, DataFrame> = ocel_summary(&ocel2_to_df(&ocel).unwrap())
+            .unwrap()
+            .into_iter()
+            .collect();
+        let sum = |name: &str, col: &str| -> u64 {
+            summary[name].column(col).unwrap().u64().unwrap().sum().unwrap()
+        };
+        assert_eq!(sum("object_types", "objects"), ocel.objects.len() as u64);
+        assert_eq!(sum("event_types", "events"), ocel.events.len() as u64);
+        assert_eq!(sum("e2o_degrees", "objects"), ocel.objects.len() as u64);
+        assert_eq!(sum("o2o_degrees", "objects"), ocel.objects.len() as u64);
+        assert_eq!(sum("objects_per_event", "events"), ocel.events.len() as u64);
+    }
+
+    /// [CaseLog] with one case per trace (given as activity names)
+    fn case_log_from_traces(traces: &[&[&str]]) -> CaseLog {
+        let mut log = CaseLog::default();
+        for (i, trace) in traces.iter().enumerate() {
+            let activities = trace
+                .iter()
+                .map(|a| match log.activities.iter().position(|b| b == a) {
+                    Some(id) => id,
+                    None => {
+                        log.activities.push(a.to_string());
+                        log.activities.len() - 1
+                    }
+                })
+                .collect();
+            log.cases.push(CaseEvents {
+                case_id: i.to_string(),
+                rows: Vec::new(),
+                activities,
+                timestamps: vec![None; trace.len()],
+            });
+        }
+        log
+    }
+
+    #[test]
+    fn test_alpha() {
+        let log = case_log_from_traces(&[&["a", "b", "c", "d"], &["a", "c", "b", "d"], &["a", "e", "d"]]);
+        let net = discover_alpha(&log, false);
+        assert_eq!(net.transitions.len(), 5);
+        // source, sink, ({a}, {b, e}), ({a}, {c, e}), ({b, e}, {d}), ({c, e}, {d})
+        assert_eq!(net.places.len(), 6);
+        assert_eq!(net.arcs.len(), 14);
+
+        let net = discover_alpha(&case_log_from_traces(&[&["a", "b", "b", "c"], &["a", "c"]]), true);
+        // source, sink and ({a}, {c}) with a self-loop of b
+        assert_eq!(net.places.len(), 3);
+        assert_eq!(net.arcs.len(), 6);
+
+        // Length-one loop without other activities: self-loop on a new place between source and sink
+        let logs: [&[&[&str]]; 2] = [&[&["a", "a"]], &[&["a", "a"], &["b"]]];
+        for traces in logs {
+            let log = case_log_from_traces(traces);
+            let net = discover_alpha(&log, true);
+            assert_eq!(net.places.len(), 3);
+            assert_eq!(net.transitions.values().filter(|t| t.label.is_none()).count(), 2);
+            let alignments = align_log(&log, &IndexedPetriNet::new(&net).unwrap());
+            assert!(alignments.iter().all(|a| a.as_ref().unwrap().cost == 0));
+        }
+
+        // Length-two loop: `a b a` and `b a b` yield places ({a}, {b}) and ({b}, {a})
+        let net = discover_alpha(&case_log_from_traces(&[&["a", "b", "a"], &["b", "a", "b"]]), true);
+        assert_eq!(net.places.len(), 4);
+        assert_eq!(net.arcs.len(), 8);
+        // Only `a b a` (a △ b, but not b △ a): a and b are parallel, so there are no places between them
+        let net = discover_alpha(&case_log_from_traces(&[&["a", "b", "a"]]), true);
+        assert_eq!(net.places.len(), 2);
+        assert_eq!(net.arcs.len(), 2);
+
+        let net = discover_alpha(&case_log_from_traces(&[&["a", "b", "b", "c"], &["a", "c"]]), true);
+        let roundtrip = petri_net_from_json(&petri_net_to_json(&net)).unwrap();
+        assert_eq!(petri_net_to_json(&roundtrip), petri_net_to_json(&net));
+
+        let df = convert_log_to_dataframe(&import_xes_file("test.xes", XESImportOptions::default()).unwrap(), false).unwrap();
+        let log = case_log_from_df(&df, &EventColumns::default()).unwrap();
+        let net = discover_alpha(&log, true);
+        assert_eq!(net.transitions.len(), log.activities.len());
+    }
+
+    #[test]
+    fn test_alpha_upstream_parity() {
+        // Alpha+ and the upstream alpha+++ implementation discover nets with the same visible transitions,
+        // which both replay the (noise-free) log perfectly
+        let event_log = import_xes_file("test.xes", XESImportOptions::default()).unwrap();
+        let projection: EventLogActivityProjection = (&event_log).into();
+        let (mut upstream, _) = alphappp_discover_petri_net(
+            &projection,
+            AlphaPPPConfig {
+                balance_thresh: 0.1,
+                fitness_thresh: 0.8,
+                replay_thresh: 0.0,
+                log_repair_skip_df_thresh_rel: 4.0,
+                log_repair_loop_df_thresh_rel: 4.0,
+                absolute_df_clean_thresh: 1,
+                relative_df_clean_thresh: 0.01,
+            },
+        );
+        // Artificial start/end activities of alpha+++ are not part of the log
+        for transition in upstream.transitions.values_mut() {
+            if transition.label.as_ref().is_some_and(|l| l.starts_with("__")) {
+                transition.label = None;
+            }
+        }
+        let log = case_log_from_df(&convert_log_to_dataframe(&event_log, false).unwrap(), &EventColumns::default()).unwrap();
+        let net = discover_alpha(&log, true);
+        let labels = |net: &PetriNet| -> HashSet<String> {
+            net.transitions.values().filter_map(|t| t.label.clone()).collect()
+        };
+        assert_eq!(labels(&net), labels(&upstream));
+        for net in [net, upstream] {
+            let alignments = align_log(&log, &IndexedPetriNet::new(&net).unwrap());
+            assert_eq!(alignment_log_fitness(&alignments), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_inductive_miner() {
+        let log = case_log_from_traces(&[&["a", "b", "c", "d"], &["a", "c", "b", "d"], &["a", "e", "d"]]);
+        let tree = discover_inductive(&log, 0.0);
+        assert_eq!(tree.to_string(), "->( 'a', X( +( 'b', 'c' ), 'e' ), 'd' )");
+        let net = tree.to_petri_net();
+        // Five activities and the parallel split/join
+        assert_eq!(net.transitions.len(), 7);
+        assert_eq!(net.transitions.values().filter(|t| t.label.is_some()).count(), 5);
+        assert_eq!(ProcessTree::from_json(&tree.to_json()).unwrap(), tree);
+
+        // c and b are mutually unreachable, so there is no sequence cut {a, c} -> {b, d},
+        // but without a, there is an exclusive choice cut {b} | {c, d} (activity concurrent fall-through)
+        let log = case_log_from_traces(&[&["a", "b"], &["c", "d"], &["a", "d"]]);
+        assert_eq!(
+            discover_inductive(&log, 0.0).to_string(),
+            "+( 'a', X( 'b', ->( X( tau, 'c' ), 'd' ) ) )"
+        );
+
+        let log = case_log_from_traces(&[&["a"], &["a", "b", "a"], &["a", "b", "a", "b", "a"]]);
+        assert_eq!(discover_inductive(&log, 0.0).to_string(), "*( 'a', 'b' )");
+
+        let mut traces: Vec<&[&str]> = vec![&["a", "b", "c"]; 10];
+        traces.push(&["a", "c"]);
+        let log = case_log_from_traces(&traces);
+        assert_eq!(discover_inductive(&log, 0.0).to_string(), "->( 'a', X( tau, 'b' ), 'c' )");
+        assert_eq!(discover_inductive(&log, 0.2).to_string(), "->( 'a', 'b', 'c' )");
+
+        let df = convert_log_to_dataframe(&import_xes_file("test.xes", XESImportOptions::default()).unwrap(), false).unwrap();
+        let log = case_log_from_df(&df, &EventColumns::default()).unwrap();
+        assert_eq!(
+            discover_inductive(&log, 0.0).to_string(),
+            "->( 'Register client', 'Mail rejection' )"
+        );
+    }
+
+    #[test]
+    fn test_inductive_miner_pm4py_parity() {
+        // Same trees as discovered by pm4py (IM, and IMf with noise threshold 0.2)
+        let log = case_log_from_traces(&[
+            &["a", "b", "c", "d"],
+            &["a", "c", "b", "d"],
+            &["a", "b", "c", "e", "f", "b", "c", "d"],
+            &["a", "c", "b", "e", "f", "c", "b", "d"],
+        ]);
+        assert_eq!(
+            discover_inductive(&log, 0.0).to_string(),
+            "->( 'a', *( +( 'b', 'c' ), ->( 'e', 'f' ) ), 'd' )"
+        );
+
+        let log = case_log_from_traces(&[
+            &["a", "b", "c", "d", "e"],
+            &["a", "b", "d", "c", "e"],
+            &["a", "c", "b", "d", "e"],
+            &["a", "c", "d", "b", "e"],
+            &["a", "d", "b", "c", "e"],
+            &["a", "d", "c", "b", "e"],
+        ]);
+        assert_eq!(discover_inductive(&log, 0.0).to_string(), "->( 'a', +( 'b', 'c', 'd' ), 'e' )");
+
+        // No cut and no activity concurrent to the rest, but `a` also starts in the middle of a trace (tau loop)
+        let log = case_log_from_traces(&[&["a", "c"], &["a", "b", "c"], &["a", "c", "b", "a", "c"]]);
+        assert_eq!(
+            discover_inductive(&log, 0.0).to_string(),
+            "*( ->( 'a', +( 'c', X( tau, 'b' ) ) ), tau )"
+        );
+
+        // IMf ignores the infrequent skip of b
+        let mut traces: Vec<&[&str]> = vec![&["a", "b", "c", "d"]; 10];
+        traces.extend(vec![&["a", "c", "b", "d"][..]; 10]);
+        traces.push(&["a", "c", "d"]);
+        let log = case_log_from_traces(&traces);
+        assert_eq!(discover_inductive(&log, 0.0).to_string(), "->( 'a', +( X( tau, 'b' ), 'c' ), 'd' )");
+        assert_eq!(discover_inductive(&log, 0.2).to_string(), "->( 'a', +( 'b', 'c' ), 'd' )");
+
+        // IMf filters the infrequent edge d -> b (ending traces after d counts as outgoing edge), which allows a sequence cut
+        traces.pop();
+        traces.push(&["a", "d", "b", "c"]);
+        let log = case_log_from_traces(&traces);
+        assert_ne!(discover_inductive(&log, 0.0).to_string(), "->( 'a', ->( +( 'b', 'c' ), 'd' ) )");
+        assert_eq!(discover_inductive(&log, 0.2).to_string(), "->( 'a', ->( +( 'b', 'c' ), 'd' ) )");
+
+        // Strict tau loop (b -> c repeated), where IMf ignores the infrequent trace ending after b
+        let mut traces: Vec<&[&str]> = vec![&["a", "b", "c"]; 10];
+        traces.extend(vec![&["a", "b", "c", "b", "c"][..]; 5]);
+        traces.push(&["a", "b"]);
+        let log = case_log_from_traces(&traces);
+        assert_eq!(
+            discover_inductive(&log, 0.0).to_string(),
+            "->( 'a', *( ->( 'b', X( tau, 'c' ) ), tau ) )"
+        );
+        assert_eq!(discover_inductive(&log, 0.2).to_string(), "->( 'a', *( ->( 'b', 'c' ), tau ) )");
+    }
+
+    #[test]
+    fn test_pnml() {
+        let pnml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<pnml>
+  <net id="net1" type="http://www.pnml.org/version-2009/grammar/pnmlcoremodel">
+    <page id="n0">
+      <place id="source"><name><text>source</text></name><initialMarking><text>1</text></initialMarking></place>
+      <place id="sink"><name><text>sink</text></name></place>
+      <transition id="t1"><name><text>a</text></name></transition>
+      <transition id="t2"><name><text>skip</text></name><toolspecific tool="ProM" version="6.4" activity="$invisible$"/></transition>
+      <arc id="a1" source="source" target="t1"><inscription><text>2</text></inscription></arc>
+      <arc id="a2" source="t1" target="sink"/>
+      <arc id="a3" source="source" target="t2"/>
+      <arc id="a4" source="t2" target="sink"/>
+    </page>
+    <finalmarkings><marking><place idref="sink"><text>1</text></place></marking></finalmarkings>
+  </net>
+</pnml>"#;
+        let net = parse_pnml(pnml.as_bytes()).unwrap();
+        assert_eq!(net.places.len(), 2);
+        let mut labels: Vec<Option<String>> = net.transitions.values().map(|t| t.label.clone()).collect();
+        labels.sort();
+        assert_eq!(labels, vec![None, Some("a".to_string())]);
+        assert_eq!(net.arcs.iter().map(|a| a.weight).collect::<Vec<_>>(), vec![2, 1, 1, 1]);
+        assert_eq!(net.initial_marking.as_ref().unwrap().values().sum::<u64>(), 1);
+        assert_eq!(net.final_markings.as_ref().unwrap().len(), 1);
+
+        let mut exported = Vec::new();
+        write_pnml(&net, &mut exported).unwrap();
+        let reimported = parse_pnml(exported.as_slice()).unwrap();
+        assert_eq!(petri_net_to_json(&reimported), petri_net_to_json(&net));
+
+        let log = case_log_from_traces(&[&["a", "b", "c"], &["a", "c", "b"], &["a"]]);
+        let net = discover_inductive(&log, 0.0).to_petri_net();
+        let mut exported = Vec::new();
+        write_pnml(&net, &mut exported).unwrap();
+        let reimported = parse_pnml(exported.as_slice()).unwrap();
+        assert_eq!(petri_net_to_json(&reimported), petri_net_to_json(&net));
+    }
+
+    #[test]
+    fn test_token_replay() {
+        let log = case_log_from_traces(&[&["a", "b", "c", "d"], &["a", "c", "b", "d"], &["a", "e", "d"]]);
+        for net in [discover_alpha(&log, false), discover_inductive(&log, 0.0).to_petri_net()] {
+            let results = token_replay(&log, &IndexedPetriNet::new(&net).unwrap());
+            assert!(results.iter().all(|r| r.is_fit()));
+            assert_eq!(log_fitness(&results), 1.0);
+        }
+
+        let net = IndexedPetriNet::new(&discover_inductive(&log, 0.0).to_petri_net()).unwrap();
+        let deviating = case_log_from_traces(&[&["a", "d"], &["a", "b", "c", "d"], &["a", "x", "e", "d"]]);
+        let results = token_replay(&deviating, &net);
+        assert!(!results[0].is_fit());
+        assert!(results[0].missing > 0 && results[0].fitness() < 1.0);
+        assert!(results[1].is_fit());
+        assert_eq!(results[2].unknown_activities, 1);
+        assert!(log_fitness(&results) < 1.0);
+
+        let df = convert_log_to_dataframe(&import_xes_file("test.xes", XESImportOptions::default()).unwrap(), false).unwrap();
+        let log = case_log_from_df(&df, &EventColumns::default()).unwrap();
+        let net = IndexedPetriNet::new(&discover_inductive(&log, 0.0).to_petri_net()).unwrap();
+        let results = token_replay(&log, &net);
+        assert_eq!(results.len(), log.cases.len());
+        assert!((0.0..=1.0).contains(&log_fitness(&results)));
+    }
+
+    #[test]
+    fn test_alignments() {
+        let log = case_log_from_traces(&[&["a", "b", "c", "d"], &["a", "c", "b", "d"], &["a", "e", "d"]]);
+        for net in [discover_alpha(&log, false), discover_inductive(&log, 0.0).to_petri_net()] {
+            let alignments = align_log(&log, &IndexedPetriNet::new(&net).unwrap());
+            assert!(alignments.iter().all(|a| a.as_ref().unwrap().cost == 0));
+            assert_eq!(alignment_log_fitness(&alignments), 1.0);
+        }
+
+        let net = IndexedPetriNet::new(&discover_inductive(&log, 0.0).to_petri_net()).unwrap();
+        let deviating = case_log_from_traces(&[
+            &["a", "d"],
+            &["a", "b", "c", "d"],
+            &["a", "x", "e", "d"],
+            &["a", "d", "e"],
+        ]);
+        let alignments: Vec<_> = align_log(&deviating, &net).into_iter().map(Option::unwrap).collect();
+        // Skipping `e` is the cheapest way to align `a, d` (worst cost: 2 log moves + 3 model moves for `a, e, d`)
+        assert_eq!(alignments[0].cost, 1);
+        assert!((alignments[0].fitness() - 0.8).abs() < 1e-9);
+        let model_moves = alignments[0]
+            .moves
+            .iter()
+            .filter(|m| matches!(m, AlignmentMove::Model(_)))
+            .count();
+        assert_eq!(model_moves, 1);
+        assert_eq!(alignments[1].cost, 0);
+        assert_eq!(alignments[2].cost, 1);
+        assert!(alignments[2].moves.iter().any(|m| matches!(m, AlignmentMove::Log(_))));
+        // `e` cannot fire after `d` (which the heuristic takes into account without losing optimality)
+        assert_eq!(alignments[3].cost, 2);
+
+        let df = convert_log_to_dataframe(&import_xes_file("test.xes", XESImportOptions::default()).unwrap(), false).unwrap();
+        let log = case_log_from_df(&df, &EventColumns::default()).unwrap();
+        let net = IndexedPetriNet::new(&discover_inductive(&log, 0.0).to_petri_net()).unwrap();
+        let alignments = align_log(&log, &net);
+        assert_eq!(alignments.len(), log.cases.len());
+        assert!(alignments.iter().all(Option::is_some));
+        assert!((0.0..=1.0).contains(&alignment_log_fitness(&alignments)));
+    }
+
     // #[test]
     // fn pm4py_test_logs() {
     //     let paths = std::fs::read_dir("../../pm4py-core/tests/input_data/").unwrap();
@@ -0,0 +1,189 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset};
+use polars::prelude::*;
+
+/// Timezone handling for datetime columns of imported DataFrames
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TimezoneOption {
+    /// Timezone-naive datetimes (representing UTC wall time)
+    Naive,
+    /// Timezone-aware datetimes in UTC (original offsets are normalized to UTC)
+    #[default]
+    Utc,
+    /// Timezone-aware datetimes converted to the given named timezone (e.g., `Europe/Berlin`)
+    Named(String),
+}
+
+impl FromStr for TimezoneOption {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "naive" => Ok(Self::Naive),
+            "utc" | "UTC" => Ok(Self::Utc),
+            "" => Err("Invalid empty timezone".to_string()),
+            tz => Ok(Self::Named(tz.to_string())),
+        }
+    }
+}
+
+impl TimezoneOption {
+    /// The option producing datetime columns with the given Polars timezone
+    pub fn from_polars_timezone(tz: Option<&TimeZone>) -> Self {
+        match tz {
+            None => TimezoneOption::Naive,
+            Some(tz) if *tz == TimeZone::UTC => TimezoneOption::Utc,
+            Some(tz) => TimezoneOption::Named(tz.to_string()),
+        }
+    }
+
+    /// The Polars timezone of datetime columns using this option
+    pub fn to_polars_timezone(&self) -> PolarsResult<Option<TimeZone>> {
+        match self {
+            TimezoneOption::Naive => Ok(None),
+            TimezoneOption::Utc => Ok(Some(TimeZone::UTC)),
+            TimezoneOption::Named(tz) => TimeZone::opt_try_new(Some(tz.as_str())),
+        }
+    }
+}
+
+///
+/// Convert all datetime columns of a [DataFrame] according to the given [TimezoneOption]
+///
+/// The represented points in time are preserved; only the timezone of the columns changes.
+///
+pub fn apply_timezone(df: &mut DataFrame, timezone: &TimezoneOption) -> PolarsResult<()> {
+    let target_tz = timezone.to_polars_timezone()?;
+    let datetime_columns: Vec<(PlSmallStr, TimeUnit)> = df
+        .get_columns()
+        .iter()
+        .filter_map(|c| match c.dtype() {
+            DataType::Datetime(unit, _) => Some((c.name().clone(), *unit)),
+            _ => None,
+        })
+        .collect();
+    for (name, unit) in datetime_columns {
+        let converted = df
+            .column(name.as_str())?
+            .cast(&DataType::Datetime(unit, target_tz.clone()))?;
+        df.with_column(converted)?;
+    }
+    Ok(())
+}
+
+/// Time unit of datetime columns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampUnit {
+    /// Nanoseconds (representable range: 1677-09-21 to 2262-04-11)
+    #[default]
+    Nanoseconds,
+    /// Microseconds
+    Microseconds,
+    /// Milliseconds
+    Milliseconds,
+}
+
+impl FromStr for TimestampUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ns" => Ok(Self::Nanoseconds),
+            "us" => Ok(Self::Microseconds),
+            "ms" => Ok(Self::Milliseconds),
+            _ => Err(format!(
+                "Invalid time unit '{s}' (expected 'ns', 'us' or 'ms')"
+            )),
+        }
+    }
+}
+
+impl TimestampUnit {
+    pub fn from_polars(unit: TimeUnit) -> Self {
+        match unit {
+            TimeUnit::Nanoseconds => TimestampUnit::Nanoseconds,
+            TimeUnit::Microseconds => TimestampUnit::Microseconds,
+            TimeUnit::Milliseconds => TimestampUnit::Milliseconds,
+        }
+    }
+
+    pub fn to_polars(self) -> TimeUnit {
+        match self {
+            TimestampUnit::Nanoseconds => TimeUnit::Nanoseconds,
+            TimestampUnit::Microseconds => TimeUnit::Microseconds,
+            TimestampUnit::Milliseconds => TimeUnit::Milliseconds,
+        }
+    }
+
+    /// Timestamp of `dt` in this unit (`None` if it is not representable as `i64`)
+    pub fn timestamp(self, dt: &DateTime<FixedOffset>) -> Option<i64> {
+        match self {
+            TimestampUnit::Nanoseconds => dt.timestamp_nanos_opt(),
+            TimestampUnit::Microseconds => Some(dt.timestamp_micros()),
+            TimestampUnit::Milliseconds => Some(dt.timestamp_millis()),
+        }
+    }
+}
+
+/// Handling of timestamps that are out of the range representable with the selected [TimestampUnit]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutOfRangePolicy {
+    /// Replace out-of-range timestamps with null
+    Null,
+    /// Clamp out-of-range timestamps to the minimum/maximum representable timestamp
+    Clamp,
+    /// Fail with an error naming the offending event/object
+    #[default]
+    Error,
+}
+
+impl FromStr for OutOfRangePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "null" => Ok(Self::Null),
+            "clamp" => Ok(Self::Clamp),
+            "error" => Ok(Self::Error),
+            _ => Err(format!(
+                "Invalid out-of-range policy '{s}' (expected 'null', 'clamp' or 'error')"
+            )),
+        }
+    }
+}
+
+/// Options for converting timestamps to Polars datetime values
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimestampOptions {
+    pub unit: TimestampUnit,
+    pub out_of_range: OutOfRangePolicy,
+}
+
+impl TimestampOptions {
+    /// Check if `dt` is representable with the selected unit
+    pub fn is_in_range(&self, dt: &DateTime<FixedOffset>) -> bool {
+        self.unit.timestamp(dt).is_some()
+    }
+
+    ///
+    /// Convert `dt` to a (timezone-naive) Polars datetime value
+    ///
+    /// Out-of-range timestamps are clamped or converted to null (for [OutOfRangePolicy::Error],
+    /// timestamps should be checked using [TimestampOptions::is_in_range] beforehand).
+    ///
+    pub fn to_any_value(&self, dt: &DateTime<FixedOffset>) -> AnyValue<'static> {
+        let unit = self.unit.to_polars();
+        match self.unit.timestamp(dt) {
+            Some(ts) => AnyValue::Datetime(ts, unit, None),
+            None => match self.out_of_range {
+                OutOfRangePolicy::Clamp => AnyValue::Datetime(
+                    if dt.timestamp() > 0 { i64::MAX } else { i64::MIN },
+                    unit,
+                    None,
+                ),
+                OutOfRangePolicy::Null | OutOfRangePolicy::Error => AnyValue::Null,
+            },
+        }
+    }
+}
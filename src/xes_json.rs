@@ -0,0 +1,264 @@
+//! JSON encoding of XES event logs
+//!
+//! A log is encoded as JSON object with the keys `attributes`, `extensions`, `classifiers`, `global_trace_attrs`,
+//! `global_event_attrs` and `traces` (each trace being an object with `attributes` and `events`, each event an object with `attributes`).
+//! Attributes are encoded as objects `{"key": ..., "type": ..., "value": ...}` with the XES types
+//! `string`, `date` (RFC 3339), `int`, `float`, `boolean`, `id`, `list` and `container` (with an array of attributes as value).
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use process_mining::{
+    convert_dataframe_to_log, convert_log_to_dataframe,
+    event_log::{
+        stream_xes::XESOuterLogData, Attribute, AttributeValue, Attributes, Event, EventLog,
+        Trace,
+    },
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    pyfunction, PyResult,
+};
+use pyo3_polars::PyDataFrame;
+use serde_json::{json, Value};
+
+use crate::{
+    ocel::parse_timestamp,
+    outer_log_data, parse_optional,
+    time::{apply_timezone, TimezoneOption},
+};
+
+fn attribute_value_to_json(value: &AttributeValue) -> (&'static str, Value) {
+    match value {
+        AttributeValue::String(s) => ("string", json!(s)),
+        AttributeValue::Date(d) => ("date", json!(d.to_rfc3339())),
+        AttributeValue::Int(i) => ("int", json!(i)),
+        AttributeValue::Float(f) => ("float", json!(f)),
+        AttributeValue::Boolean(b) => ("boolean", json!(b)),
+        AttributeValue::ID(id) => ("id", json!(id.to_string())),
+        AttributeValue::List(l) => ("list", attributes_to_json(l)),
+        AttributeValue::Container(c) => ("container", attributes_to_json(c)),
+        AttributeValue::None() => ("null", Value::Null),
+    }
+}
+
+/// Encode XES attributes as JSON array
+pub fn attributes_to_json(attributes: &[Attribute]) -> Value {
+    Value::Array(
+        attributes
+            .iter()
+            .map(|a| {
+                let (value_type, value) = attribute_value_to_json(&a.value);
+                json!({"key": a.key, "type": value_type, "value": value})
+            })
+            .collect(),
+    )
+}
+
+fn attribute_value_from_json(value_type: &str, value: &Value) -> Result<AttributeValue, String> {
+    let invalid = || format!("Invalid {value_type} attribute value {value}");
+    Ok(match value_type {
+        "string" => AttributeValue::String(value.as_str().ok_or_else(invalid)?.to_string()),
+        "date" => AttributeValue::Date(parse_timestamp(value.as_str().ok_or_else(invalid)?)?),
+        "int" => AttributeValue::Int(value.as_i64().ok_or_else(invalid)?),
+        "float" => AttributeValue::Float(value.as_f64().ok_or_else(invalid)?),
+        "boolean" => AttributeValue::Boolean(value.as_bool().ok_or_else(invalid)?),
+        "id" => AttributeValue::ID(
+            value
+                .as_str()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(invalid)?,
+        ),
+        "list" => AttributeValue::List(attributes_from_json(Some(value))?),
+        "container" => AttributeValue::Container(attributes_from_json(Some(value))?),
+        "null" => AttributeValue::None(),
+        _ => return Err(format!("Invalid attribute type '{value_type}'")),
+    })
+}
+
+/// Decode XES attributes from a JSON array (a missing value is decoded as no attributes)
+pub fn attributes_from_json(value: Option<&Value>) -> Result<Attributes, String> {
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+    value
+        .as_array()
+        .ok_or_else(|| format!("Expected array of attributes, got {value}"))?
+        .iter()
+        .map(|a| {
+            let key = a
+                .get("key")
+                .and_then(Value::as_str)
+                .ok_or_else(|| format!("Missing attribute key in {a}"))?;
+            let value_type = a
+                .get("type")
+                .and_then(Value::as_str)
+                .ok_or_else(|| format!("Missing attribute type in {a}"))?;
+            let value = attribute_value_from_json(value_type, a.get("value").unwrap_or(&Value::Null))?;
+            Ok(Attribute::new(key.to_string(), value))
+        })
+        .collect()
+}
+
+///
+/// Encode an [EventLog] as JSON (see module documentation for the layout)
+///
+pub fn event_log_to_json(log: &EventLog) -> Value {
+    json!({
+        "attributes": attributes_to_json(&log.attributes),
+        "extensions": log.extensions.clone().unwrap_or_default(),
+        "classifiers": log.classifiers.clone().unwrap_or_default(),
+        "global_trace_attrs": attributes_to_json(log.global_trace_attrs.as_deref().unwrap_or_default()),
+        "global_event_attrs": attributes_to_json(log.global_event_attrs.as_deref().unwrap_or_default()),
+        "traces": log.traces.iter().map(|t| json!({
+            "attributes": attributes_to_json(&t.attributes),
+            "events": t.events.iter().map(|e| json!({
+                "attributes": attributes_to_json(&e.attributes),
+            })).collect::<Vec<_>>(),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+///
+/// Decode an [EventLog] from JSON (see module documentation for the layout)
+///
+pub fn event_log_from_json(value: &Value) -> Result<EventLog, String> {
+    let traces = value
+        .get("traces")
+        .and_then(Value::as_array)
+        .ok_or_else(|| "Missing 'traces'".to_string())?
+        .iter()
+        .map(|t| {
+            Ok(Trace {
+                attributes: attributes_from_json(t.get("attributes"))?,
+                events: t
+                    .get("events")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .map(|e| {
+                        Ok(Event {
+                            attributes: attributes_from_json(e.get("attributes"))?,
+                        })
+                    })
+                    .collect::<Result<_, String>>()?,
+            })
+        })
+        .collect::<Result<_, String>>()?;
+    Ok(EventLog {
+        attributes: attributes_from_json(value.get("attributes"))?,
+        traces,
+        extensions: value
+            .get("extensions")
+            .map(|e| serde_json::from_value(e.clone()))
+            .transpose()
+            .map_err(|e| format!("Invalid extensions: {e}"))?,
+        classifiers: value
+            .get("classifiers")
+            .map(|c| serde_json::from_value(c.clone()))
+            .transpose()
+            .map_err(|e| format!("Invalid classifiers: {e}"))?,
+        global_trace_attrs: value
+            .get("global_trace_attrs")
+            .map(|a| attributes_from_json(Some(a)))
+            .transpose()?,
+        global_event_attrs: value
+            .get("global_event_attrs")
+            .map(|a| attributes_from_json(Some(a)))
+            .transpose()?,
+    })
+}
+
+///
+/// Import an XES JSON file (`.json` or `.json.gz`)
+///
+pub fn import_xes_json_file(path: &str) -> Result<EventLog, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {path}: {e}"))?;
+    let reader: Box<dyn Read> = if path.ends_with(".gz") {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    let value: Value = serde_json::from_reader(BufReader::new(reader))
+        .map_err(|e| format!("Failed to parse JSON: {e}"))?;
+    event_log_from_json(&value)
+}
+
+///
+/// Export an [EventLog] to an XES JSON file (`.json` or `.json.gz`)
+///
+pub fn export_xes_json_file(log: &EventLog, path: &str) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("Failed to create {path}: {e}"))?;
+    let json = event_log_to_json(log);
+    if path.ends_with(".gz") {
+        let encoder = write_json(GzEncoder::new(file, Compression::default()), &json)?;
+        // Explicitly finish the gzip stream, so that errors writing the trailer are not lost on drop
+        encoder
+            .finish()
+            .map_err(|e| format!("Failed to write JSON: {e}"))?;
+    } else {
+        write_json(file, &json)?;
+    }
+    Ok(())
+}
+
+/// Write `value` as JSON to `writer` (buffered) and return the flushed writer
+fn write_json<W: Write>(writer: W, value: &Value) -> Result<W, String> {
+    let mut writer = BufWriter::new(writer);
+    serde_json::to_writer(&mut writer, value).map_err(|e| format!("Failed to write JSON: {e}"))?;
+    writer
+        .into_inner()
+        .map_err(|e| format!("Failed to write JSON: {}", e.error()))
+}
+
+///
+/// Import an XES JSON event log
+///
+/// Returns a tuple of a Polars [DataFrame] for the event data and a json-encoding of all log attributes/extensions/classifiers (as `import_xes_rs`)
+///
+/// * `path` - The filepath of the .json or .json.gz file to import
+/// * `timezone` - Optional timezone handling for datetime columns: `"utc"` (default, timezone-aware UTC), `"naive"` or a named timezone (e.g., `"Europe/Berlin"`)
+///
+#[pyfunction]
+#[pyo3(signature = (path, timezone=None))]
+pub fn import_xes_json_rs(path: String, timezone: Option<String>) -> PyResult<(PyDataFrame, String)> {
+    let timezone: Option<TimezoneOption> = parse_optional(timezone)?;
+    let log = import_xes_json_file(&path).map_err(PyValueError::new_err)?;
+    let mut df = convert_log_to_dataframe(&log, false)
+        .map_err(|e| PyTypeError::new_err(format!("Failed to convert log to dataframe: {e:?}")))?;
+    if let Some(timezone) = timezone {
+        apply_timezone(&mut df, &timezone)
+            .map_err(|e| PyValueError::new_err(format!("Failed to apply timezone: {e:?}")))?;
+    }
+    Ok((
+        PyDataFrame(df),
+        serde_json::to_string(&outer_log_data(&log)).unwrap(),
+    ))
+}
+
+///
+/// Export an event log DataFrame to an XES JSON file
+///
+/// * `df` - The Polars DataFrame representing the event log
+/// * `path` - The filepath the .json or .json.gz file should be written to
+/// * `log_data` - Optional json-encoding of the log attributes/extensions/classifiers (as returned by `import_xes_rs`)
+///
+#[pyfunction]
+#[pyo3(signature = (df, path, log_data=None))]
+pub fn export_xes_json_rs(df: PyDataFrame, path: String, log_data: Option<String>) -> PyResult<()> {
+    let df: polars::frame::DataFrame = df.into();
+    let mut log = convert_dataframe_to_log(&df)
+        .map_err(|e| PyTypeError::new_err(format!("Failed to convert dataframe to log: {e:?}")))?;
+    if let Some(log_data) = log_data {
+        let log_data: XESOuterLogData = serde_json::from_str(&log_data)
+            .map_err(|e| PyValueError::new_err(format!("Invalid log data: {e}")))?;
+        log.attributes = log_data.log_attributes;
+        log.extensions = Some(log_data.extensions);
+        log.classifiers = Some(log_data.classifiers);
+        log.global_trace_attrs = Some(log_data.global_trace_attrs);
+        log.global_event_attrs = Some(log_data.global_event_attrs);
+    }
+    export_xes_json_file(&log, &path).map_err(PyTypeError::new_err)
+}